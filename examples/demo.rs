@@ -13,9 +13,9 @@ fn main() {
     println!("Parsing time tracking data...\n");
     let data = parse_time_tracking_data(input, None, None);
 
-    if !data.warnings.is_empty() {
+    if !data.all_warnings().is_empty() {
         println!("Warnings:");
-        for warning in &data.warnings {
+        for warning in &data.all_warnings() {
             println!("  - {warning}");
         }
         println!();
@@ -30,46 +30,3 @@ fn main() {
         Err(e) => println!("Error serializing to JSON: {e}"),
     }
 }
-
-/// Generate sample output for testing/comparison (as requested)
-pub fn generate_sample_output(data: &TimeTrackingData) -> String {
-    let mut output = String::new();
-
-    if let (Some(start), Some(end)) = (&data.start_time, &data.end_time) {
-        output.push_str(&format!(
-            "Start Time: {} End Time: {}\n",
-            format_time(start),
-            format_time(end)
-        ));
-    }
-
-    output.push_str(&format!(
-        "Total Working Time: {} ({} hrs)\n",
-        Time::format_duration_minutes(data.total_minutes),
-        Time::format_duration_decimal(data.total_minutes)
-    ));
-
-    output.push_str(&format!(
-        "Total dead time: {} ({} hrs)\n",
-        Time::format_duration_minutes(data.dead_time_minutes),
-        Time::format_duration_decimal(data.dead_time_minutes)
-    ));
-
-    output.push('\n');
-
-    for project in &data.projects {
-        output.push_str(&format!(
-            "Billing Code: {} - {} ({} hrs)\n",
-            project.name,
-            Time::format_duration_minutes(project.total_minutes),
-            Time::format_duration_decimal(project.total_minutes)
-        ));
-
-        for note in &project.notes {
-            output.push_str(&format!("- {note}\n"));
-        }
-        output.push('\n');
-    }
-
-    output
-}
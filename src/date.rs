@@ -0,0 +1,140 @@
+use std::{fmt::Display, str::FromStr};
+
+use super::*;
+
+/// Represents a calendar date attached to time tracking entries.
+/// The year is optional since short prefixes like "6/3" omit it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Date {
+    pub year: Option<u16>,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    pub fn new(year: Option<u16>, month: u8, day: u8) -> Result<Self, String> {
+        if !(1..=12).contains(&month) {
+            return Err(format!("Month must be between 1 and 12, got {month}"));
+        }
+        if !(1..=31).contains(&day) {
+            return Err(format!("Day must be between 1 and 31, got {day}"));
+        }
+        Ok(Date { year, month, day })
+    }
+
+    /// The following calendar day. A date without a year is treated as a non-leap year.
+    pub fn next_day(&self) -> Date {
+        let last_day = days_in_month(self.year.unwrap_or(1), self.month);
+        match (self.day >= last_day, self.month) {
+            (false, _) => Date {
+                day: self.day + 1,
+                ..*self
+            },
+            (true, 12) => Date {
+                year: self.year.map(|year| year + 1),
+                month: 1,
+                day: 1,
+            },
+            (true, month) => Date {
+                month: month + 1,
+                day: 1,
+                ..*self
+            },
+        }
+    }
+
+    /// The UTC date of a Unix timestamp (seconds since 1970-01-01T00:00:00Z)
+    pub fn from_unix_seconds(seconds: u64) -> Date {
+        let days = (seconds / 86_400) as i64;
+
+        // Convert days since 1970-01-01 to a civil date (Howard Hinnant's algorithm)
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Date {
+            year: Some(year as u16),
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+
+    /// Parse an ISO 8601 calendar date like "2024-03-04"
+    pub fn from_iso(s: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid ISO date format: {s}");
+        let mut parts = s.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        if year.len() != 4 {
+            return Err(invalid());
+        }
+
+        Date::new(
+            Some(year.parse().map_err(|_| invalid())?),
+            month.parse().map_err(|_| invalid())?,
+            day.parse().map_err(|_| invalid())?,
+        )
+    }
+}
+
+/// Number of days in the given month, accounting for leap years
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    /// Parse a date like "6/3" or "6/3/2024"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('/');
+        let month = parts
+            .next()
+            .and_then(|m| m.parse().ok())
+            .ok_or_else(|| format!("Invalid date format: {s}"))?;
+        let day = parts
+            .next()
+            .and_then(|d| d.parse().ok())
+            .ok_or_else(|| format!("Invalid date format: {s}"))?;
+        let year = parts
+            .next()
+            .map(|y| y.parse().map_err(|_| format!("Invalid date format: {s}")))
+            .transpose()?;
+        if parts.next().is_some() {
+            return Err(format!("Invalid date format: {s}"));
+        }
+
+        Date::new(year, month, day)
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.year {
+            Some(year) => write!(f, "{}/{}/{}", self.month, self.day, year),
+            None => write!(f, "{}/{}", self.month, self.day),
+        }
+    }
+}
@@ -6,13 +6,53 @@ pub fn format_time_option(time: Option<&Time>, fallback: &str) -> String {
     }
     let time = time.unwrap();
     if time.hour == 0 {
-        format!("00:{}", time.minute)
+        format!("00:{:02}", time.minute.get())
     } else {
-        format!("{}:{}", time.hour, time.minute)
+        format_time(time)
     }
 }
 
-/// Helper function to format a Time struct as a string
+/// Helper function to format a Time struct as a string, always with two-digit minutes
 pub fn format_time(time: &Time) -> String {
-    format!("{}:{}", time.hour, time.minute)
+    format!("{}:{:02}", time.hour, time.minute.get())
+}
+
+/// Like `format_time`, but leaves off ":00" for a time on the hour ("8" rather than "8:00")
+pub fn format_time_compact(time: &Time) -> String {
+    if time.minute == 0 {
+        time.hour.to_string()
+    } else {
+        format_time(time)
+    }
+}
+
+/// Format a duration in minutes as an ISO 8601 duration (e.g. "PT7H30M")
+pub fn iso8601_duration(minutes: u32) -> String {
+    let hours = minutes / 60;
+    let mins = minutes % 60;
+    match (hours, mins) {
+        (0, mins) => format!("PT{mins}M"),
+        (hours, 0) => format!("PT{hours}H"),
+        (hours, mins) => format!("PT{hours}H{mins}M"),
+    }
+}
+
+/// Format a duration in minutes the way duration-only entries are written (e.g. "1h30m")
+pub fn format_duration_hm(minutes: u32) -> String {
+    let hours = minutes / 60;
+    let mins = minutes % 60;
+    match (hours, mins) {
+        (0, mins) => format!("{mins}m"),
+        (hours, 0) => format!("{hours}h"),
+        (hours, mins) => format!("{hours}h{mins}m"),
+    }
+}
+
+/// Truncate text to at most `max_chars` characters, appending "…" when shortened.
+/// Counts chars rather than bytes so multi-byte text is never split.
+pub fn truncate_chars(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((index, _)) => format!("{}…", &text[..index]),
+        None => text.to_string(),
+    }
 }
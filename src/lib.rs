@@ -1,15 +1,31 @@
 // used by sub-modules
 use serde::{Deserialize, Serialize};
 
+mod date;
 mod format;
+mod parse_error;
+mod parse_warning;
 mod parser;
+mod parser_config;
 mod project_summary;
+mod report;
+mod rounding_mode;
 mod time;
 mod time_entry;
+mod time_parse_error;
 mod time_tracking_data;
+mod validation_config;
+pub use date::*;
 pub use format::*;
+pub use parse_error::*;
+pub use parse_warning::*;
 pub use parser::*;
+pub use parser_config::*;
 pub use project_summary::*;
+pub use report::*;
+pub use rounding_mode::*;
 pub use time::*;
 pub use time_entry::*;
+pub use time_parse_error::*;
 pub use time_tracking_data::*;
+pub use validation_config::*;
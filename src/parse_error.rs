@@ -0,0 +1,30 @@
+use std::fmt::Display;
+
+/// Error returned by the strict parsing entry points
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// The offending input line (empty when the input had no entry lines at all)
+    pub line: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new<T: Into<String>, U: Into<String>>(line: T, reason: U) -> Self {
+        ParseError {
+            line: line.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.line.is_empty() {
+            write!(f, "{}", self.reason)
+        } else {
+            write!(f, "{} (line: '{}')", self.reason, self.line)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -0,0 +1,241 @@
+use std::{fmt::Display, ops::Range};
+
+use super::*;
+
+/// A problem found while parsing or validating time tracking input.
+/// Every warning carries the 1-based line number in the original input it refers to.
+/// Warnings raised while reading a line also carry the byte range of the offending text
+/// in the input (see `span`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ParseWarning {
+    /// An entry line had a time range but no project name
+    MissingProjectName {
+        line_number: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        span: Option<Range<usize>>,
+        line: String,
+    },
+    /// The time range at the start of an entry line couldn't be parsed
+    InvalidTimeRange {
+        line_number: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        span: Option<Range<usize>>,
+        raw: String,
+        reason: String,
+    },
+    /// A single entry is suspiciously long, which usually means the input is out of order
+    LongDuration {
+        line_number: usize,
+        start: Time,
+        end: Time,
+        minutes: u32,
+        /// The `ValidationConfig::max_entry_minutes` threshold that was exceeded
+        #[serde(default)]
+        limit_minutes: u32,
+    },
+    /// The gap between two entries is suspiciously long (reported on the later entry's line)
+    LargeGap {
+        line_number: usize,
+        from: Time,
+        to: Time,
+        minutes: u32,
+        /// The `ValidationConfig::max_gap_minutes` threshold that was exceeded
+        #[serde(default)]
+        limit_minutes: u32,
+    },
+    /// An entry starts and ends at the same time, which usually means a typo
+    ZeroDuration {
+        line_number: usize,
+        start: Time,
+        end: Time,
+    },
+    /// A line was longer than `ParserConfig::max_line_length` and was skipped
+    LineTooLong {
+        line_number: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        span: Option<Range<usize>>,
+        length: usize,
+        limit: usize,
+    },
+    /// An entry starts before the previous entry did, so the input is out of order
+    EntriesOutOfOrder {
+        line_number: usize,
+        previous_start: Time,
+        start: Time,
+    },
+    /// In strict mode, an entry or gap between two times without AM/PM that only makes
+    /// sense by assuming it crosses noon or midnight
+    AmbiguousTime {
+        line_number: usize,
+        from: Time,
+        to: Time,
+    },
+    /// An open-ended entry ("2- meeting") that was skipped because it wasn't the last
+    /// entry or no current time was given to end it
+    OpenEndedEntry {
+        line_number: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        span: Option<Range<usize>>,
+        start: Time,
+    },
+    /// The total working time is over 24 hours, which usually means more than one day
+    /// was pasted in. Reported on the line of the entry that pushed the total over.
+    TotalExceedsDay {
+        line_number: usize,
+        total_minutes: u32,
+    },
+    /// With `StopMode::Never`, a line that is neither an entry nor a bulleted note was
+    /// skipped
+    UnrecognizedLine {
+        line_number: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        span: Option<Range<usize>>,
+        line: String,
+    },
+    /// An entry repeats the previous entry's start, end and project exactly, which
+    /// usually means a line was pasted twice
+    DuplicateEntry {
+        line_number: usize,
+        start: Time,
+        end: Time,
+        project: String,
+    },
+}
+
+impl ParseWarning {
+    /// Byte range in the original input of the text this warning is about (e.g. the
+    /// "25:70-8" of an invalid time range), for underlining it in an editor. Only set for
+    /// warnings raised while reading a line, not for ones found by validating entries.
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            ParseWarning::MissingProjectName { span, .. }
+            | ParseWarning::InvalidTimeRange { span, .. }
+            | ParseWarning::LineTooLong { span, .. }
+            | ParseWarning::OpenEndedEntry { span, .. }
+            | ParseWarning::UnrecognizedLine { span, .. } => span.clone(),
+            ParseWarning::LongDuration { .. }
+            | ParseWarning::LargeGap { .. }
+            | ParseWarning::ZeroDuration { .. }
+            | ParseWarning::EntriesOutOfOrder { .. }
+            | ParseWarning::AmbiguousTime { .. }
+            | ParseWarning::TotalExceedsDay { .. }
+            | ParseWarning::DuplicateEntry { .. } => None,
+        }
+    }
+
+    /// The 1-based line number in the original input this warning refers to
+    pub fn line_number(&self) -> usize {
+        match self {
+            ParseWarning::MissingProjectName { line_number, .. }
+            | ParseWarning::InvalidTimeRange { line_number, .. }
+            | ParseWarning::LongDuration { line_number, .. }
+            | ParseWarning::LargeGap { line_number, .. }
+            | ParseWarning::ZeroDuration { line_number, .. }
+            | ParseWarning::LineTooLong { line_number, .. }
+            | ParseWarning::EntriesOutOfOrder { line_number, .. }
+            | ParseWarning::AmbiguousTime { line_number, .. }
+            | ParseWarning::OpenEndedEntry { line_number, .. }
+            | ParseWarning::TotalExceedsDay { line_number, .. }
+            | ParseWarning::UnrecognizedLine { line_number, .. }
+            | ParseWarning::DuplicateEntry { line_number, .. } => *line_number,
+        }
+    }
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseWarning::MissingProjectName { line, .. } => {
+                write!(f, "Line missing project name: {line}")?
+            }
+            ParseWarning::InvalidTimeRange { raw, reason, .. } => {
+                write!(f, "Error parsing time range '{raw}': {reason}")?
+            }
+            ParseWarning::LongDuration {
+                start,
+                end,
+                limit_minutes,
+                ..
+            } => write!(
+                f,
+                "Time period {}-{} appears to be longer than {}. Input may not be in correct order.",
+                format_time(start),
+                format_time(end),
+                describe_limit(*limit_minutes)
+            )?,
+            ParseWarning::LargeGap {
+                from,
+                to,
+                limit_minutes,
+                ..
+            } => write!(
+                f,
+                "Gap from {} to {} appears to be longer than {}. Input may not be in correct order.",
+                format_time(from),
+                format_time(to),
+                describe_limit(*limit_minutes)
+            )?,
+            ParseWarning::ZeroDuration { start, end, .. } => write!(
+                f,
+                "Entry {}-{} has zero duration",
+                format_time(start),
+                format_time(end)
+            )?,
+            ParseWarning::LineTooLong { length, limit, .. } => write!(
+                f,
+                "Skipped line of {length} bytes, over the {limit} byte limit"
+            )?,
+            ParseWarning::EntriesOutOfOrder {
+                previous_start,
+                start,
+                ..
+            } => write!(
+                f,
+                "Entry starting at {} begins before the previous entry at {}. Input may not be in correct order.",
+                format_time(start),
+                format_time(previous_start)
+            )?,
+            ParseWarning::AmbiguousTime { from, to, .. } => write!(
+                f,
+                "Going from {} to {} assumes crossing noon or midnight. Add am/pm to make it unambiguous.",
+                format_time(from),
+                format_time(to)
+            )?,
+            ParseWarning::OpenEndedEntry { start, .. } => write!(
+                f,
+                "Skipped open-ended entry starting at {}. Only the last entry can be open-ended, and only when the current time is known.",
+                format_time(start)
+            )?,
+            ParseWarning::TotalExceedsDay { total_minutes, .. } => write!(
+                f,
+                "Total working time of {} is over 24 hours. Input may contain more than one day.",
+                Time::format_duration_minutes(*total_minutes)
+            )?,
+            ParseWarning::UnrecognizedLine { line, .. } => {
+                write!(f, "Skipped line that isn't an entry or a note: {line}")?
+            }
+            ParseWarning::DuplicateEntry {
+                start,
+                end,
+                project,
+                ..
+            } => write!(
+                f,
+                "Entry {}-{} {project} repeats the previous entry. It may have been pasted twice.",
+                format_time(start),
+                format_time(end)
+            )?,
+        }
+        write!(f, " (line {})", self.line_number())
+    }
+}
+
+/// Describe a warning threshold as "8 hours", "1 hour" or "90 minutes"
+fn describe_limit(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (1, 0) => "1 hour".to_string(),
+        (hours, 0) => format!("{hours} hours"),
+        _ => format!("{minutes} minutes"),
+    }
+}
@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use std::io::{self, BufRead};
+use std::ops::Range;
 
 use std::sync::OnceLock;
 use strip_prefix_suffix_sane::StripPrefixSuffixSane;
@@ -6,155 +8,1288 @@ use strip_prefix_suffix_sane::StripPrefixSuffixSane;
 use super::*;
 
 static TIME_REGEX: OnceLock<regex::Regex> = OnceLock::new();
+static DATE_PREFIX_REGEX: OnceLock<regex::Regex> = OnceLock::new();
+static DURATION_REGEX: OnceLock<regex::Regex> = OnceLock::new();
+static RANGE_SEPARATOR_REGEX: OnceLock<regex::Regex> = OnceLock::new();
 
-/// Parse a time string like "7:30" or "7"
-fn parse_time(time_str: &str) -> Result<Time, String> {
+/// A single time like "7", "7:30", "7:30pm" or "noon"
+const TIME_PATTERN: &str =
+    r"(?:\d{1,2}(?::\d{2}(?::\d{2})?)?(?:[aApP]\.?(?:[mM]\.?)?)?|(?i:noon|midnight))";
+/// The start of the time ending a range
+const END_TIME_PATTERN: &str = r"(?:\d{1,2}(?::\d{2}(?::\d{2})?)?|(?i:noon|midnight))";
+/// Separators accepted between the start and end of a range: "-", an en or em dash, or " to "
+const RANGE_SEPARATOR_PATTERN: &str = r"(?:-|\s*[\u{2013}\u{2014}]\s*|\s+to\s+)";
+
+fn time_regex() -> &'static regex::Regex {
+    // Match time patterns like "10-2" or "10:30-3:45", with or without project name,
+    // as well as open-ended ranges like "2- meeting"
+    TIME_REGEX.get_or_init(|| {
+        regex::Regex::new(&format!(
+            r"^{TIME_PATTERN}(?:{RANGE_SEPARATOR_PATTERN}{END_TIME_PATTERN}|-(?:\s|$))"
+        ))
+        .expect("could not compile regex")
+    })
+}
+
+/// Where `normalize_range_separator` rewrote a separator: the byte index of the "-" in the
+/// rewritten line, and how many bytes longer the separator was in the original
+#[derive(Clone, Copy, Default)]
+struct SeparatorRewrite {
+    at: usize,
+    extra: usize,
+}
+
+impl SeparatorRewrite {
+    /// Map a byte position in the rewritten line back to the original line
+    fn original_position(self, position: usize) -> usize {
+        if position > self.at {
+            position + self.extra
+        } else {
+            position
+        }
+    }
+}
+
+/// Rewrite a leading range written with an en/em dash or " to " (e.g. "8 to 9 project")
+/// to use a plain "-", so the rest of the parser only deals with one separator
+fn normalize_range_separator(line: &str) -> (std::borrow::Cow<'_, str>, SeparatorRewrite) {
+    let regex = RANGE_SEPARATOR_REGEX.get_or_init(|| {
+        regex::Regex::new(&format!(
+            r"^({TIME_PATTERN}){RANGE_SEPARATOR_PATTERN}({END_TIME_PATTERN})"
+        ))
+        .expect("could not compile regex")
+    });
+    match regex.captures(line) {
+        Some(captures) => {
+            let (start, end) = (&captures[1], captures.get(2).expect("end time group"));
+            let rewrite = SeparatorRewrite {
+                at: start.len(),
+                extra: end.start() - start.len() - 1,
+            };
+            let rewritten = format!("{start}-{}", &line[end.start()..]);
+            (std::borrow::Cow::Owned(rewritten), rewrite)
+        }
+        None => (
+            std::borrow::Cow::Borrowed(line),
+            SeparatorRewrite::default(),
+        ),
+    }
+}
+
+fn duration_regex() -> &'static regex::Regex {
+    // Match durations like "30m", "1h", "1h5m" or decimal hours like "1.5" or "1½"
+    // followed by whitespace or the end of the line
+    DURATION_REGEX.get_or_init(|| {
+        regex::Regex::new(r"^(?:(\d+)h(?:(\d+)m)?|(\d+)m|(\d*\.\d+|\d*[½¼¾]))(?:\s|$)")
+            .expect("could not compile regex")
+    })
+}
+
+/// Check if text starts with something an entry can begin with: a time range or a duration
+fn starts_with_entry(text: &str) -> bool {
+    time_regex().is_match(text) || duration_regex().is_match(text)
+}
+
+/// Parse decimal hours like "1.5", or whole hours with a fraction glyph like "1½" or "¾"
+fn parse_decimal_hours(hours_str: &str) -> Option<f64> {
+    let fraction = match hours_str.chars().last()? {
+        '½' => 0.5,
+        '¼' => 0.25,
+        '¾' => 0.75,
+        _ => return hours_str.parse().ok(),
+    };
+    let whole = &hours_str[..hours_str.len() - '½'.len_utf8()];
+    let whole: f64 = if whole.is_empty() {
+        0.0
+    } else {
+        whole.parse().ok()?
+    };
+    Some(whole + fraction)
+}
+
+/// Parse a duration like "30m", "1h", "1h30m" or "1.5" / "1½" (decimal hours) into minutes.
+/// Returns `None` when the text isn't a duration, and an error for decimal hours over 24.
+fn parse_duration(duration_str: &str) -> Option<Result<u32, String>> {
+    let captures = duration_regex().captures(duration_str)?;
+    if captures[0].len() != duration_str.len() {
+        return None;
+    }
+
+    if let Some(hours) = captures.get(4) {
+        let hours_str = hours.as_str();
+        let hours = parse_decimal_hours(hours_str)?;
+        if hours > 24.0 {
+            return Some(Err(format!(
+                "Decimal hours must be at most 24, got {hours_str}"
+            )));
+        }
+        return Some(Ok((hours * 60.0).round() as u32));
+    }
+
+    let number = |index: usize| {
+        captures
+            .get(index)
+            .map_or(Some(0), |value| value.as_str().parse::<u32>().ok())
+    };
+    Some(Ok(number(1)? * 60 + number(2)? + number(3)?))
+}
+
+/// Parse the keywords "noon" and "midnight" (case-insensitive) as 12:00pm and 12:00am.
+/// Like any 12:00 they count as the start of a 12-hour period, so "11-noon" is 60 minutes
+/// and "midnight-1" is 60 minutes.
+fn parse_time_keyword(time_str: &str) -> Option<Time> {
+    let meridiem = if time_str.eq_ignore_ascii_case("noon") {
+        Meridiem::Pm
+    } else if time_str.eq_ignore_ascii_case("midnight") {
+        Meridiem::Am
+    } else {
+        return None;
+    };
+    Some(Time::new(12, 0).ok()?.with_meridiem(meridiem))
+}
+
+/// Parse a time string like "7:30", "7", "7:30pm" or "noon"
+pub(crate) fn parse_time(time_str: &str) -> Result<Time, String> {
+    if let Some(time) = parse_time_keyword(time_str) {
+        return Ok(time);
+    }
+    let (time_str, meridiem) = Meridiem::split_suffix(time_str);
     let mut parts = time_str.split(':');
 
     let hour = parts
         .next()
         .ok_or_else(|| format!("Invalid time format: {time_str}"))?;
     let minute = parts.next().unwrap_or("00");
+    let second = parts.next();
+    if parts.next().is_some() {
+        return Err(format!("Invalid time format: {time_str}"));
+    }
 
-    Time::from_strings(hour, minute)
+    // Hour zero only exists on a 24-hour clock, so "00:30" is read as 12:30am
+    if hour.parse::<Hour>().is_ok_and(|hour| hour == 0) {
+        if meridiem == Some(Meridiem::Pm) {
+            return Err(format!("Hour 0 can't be pm: {time_str}pm"));
+        }
+        let minute: Minute = minute.parse()?;
+        return with_optional_second(Time::new_24(0, minute.get())?, second);
+    }
+
+    let time = with_optional_second(Time::from_strings(hour, minute)?, second)?;
+    Ok(match meridiem {
+        Some(meridiem) => time.with_meridiem(meridiem),
+        None => time,
+    })
+}
+
+/// Parse a 24-hour time string like "13:30" or "9"
+fn parse_time_24(time_str: &str) -> Result<Time, String> {
+    if let Some(time) = parse_time_keyword(time_str) {
+        return Ok(time);
+    }
+    if let (_, Some(meridiem)) = Meridiem::split_suffix(time_str) {
+        return Err(format!(
+            "Unexpected '{meridiem}' in 24-hour time: {time_str}"
+        ));
+    }
+
+    let (hour, minute) = time_str.split_once(':').unwrap_or((time_str, "00"));
+    let (minute, second) = match minute.split_once(':') {
+        Some((minute, second)) => (minute, Some(second)),
+        None => (minute, None),
+    };
+    let hour: u8 = hour
+        .parse()
+        .map_err(|_| format!("Invalid hour format: {hour}"))?;
+    let minute: u8 = minute
+        .parse()
+        .map_err(|_| format!("Invalid minute format: {minute}"))?;
+
+    with_optional_second(Time::new_24(hour, minute)?, second)
+}
+
+/// Attach the seconds part of a time like "7:30:15", if there is one
+fn with_optional_second(time: Time, second: Option<&str>) -> Result<Time, String> {
+    match second {
+        Some(second) => Ok(time.with_second(second.parse()?)),
+        None => Ok(time),
+    }
+}
+
+/// Parse a single time, on the 24-hour clock when configured
+fn parse_config_time(time_str: &str, config: &ParserConfig) -> Result<Time, String> {
+    if config.twenty_four_hour {
+        parse_time_24(time_str)
+    } else {
+        parse_time(time_str)
+    }
 }
 
-/// Parse a time range like "7:30-8" or "8-8:30"
-fn parse_time_range(range_str: &str) -> Result<(Time, Time), String> {
+/// Parse a time range like "7:30-8" or "8-8:30".
+/// An end time marked "+1d" (or "+1") is on the next day, e.g. "10-2+1d" is 10pm to 2am.
+fn parse_time_range(range_str: &str, config: &ParserConfig) -> Result<(Time, Time), String> {
     let (start, end) = range_str
         .split_once('-')
         .ok_or_else(|| format!("Invalid time range format: {range_str}"))?;
 
+    if let Some(end) = end.strip_suffix("+1d").or_else(|| end.strip_suffix("+1")) {
+        let (start, end) = parse_time_range(&format!("{start}-{end}"), config)?;
+        return Ok((
+            start.with_default_meridiem(Meridiem::Pm),
+            end.with_default_meridiem(Meridiem::Am),
+        ));
+    }
+
+    if config.twenty_four_hour {
+        return Ok((parse_time_24(start.trim())?, parse_time_24(end.trim())?));
+    }
+
     let start = parse_time(start.trim())?;
     let end = parse_time(end.trim())?;
 
     Ok((start, end))
 }
 
-/// Check if a line looks like a time tracking entry (e.g., "10-2 project" or "10:30-3 project")
-/// This includes lines that have the time pattern but might be missing the project name
-fn is_time_tracking_line(line: &str, prefix: Option<&str>) -> bool {
-    // Use regex to match time patterns like "10-2" or "10:30-3:45", with or without project name
-    let regex = TIME_REGEX.get_or_init(|| {
-        regex::Regex::new(r"^\d{1,2}(?::\d{2})?-\d{1,2}(?::\d{2})?")
-            .expect("could not compile regex")
+/// Split a leading date like "6/3" or "6/3/2024" off an entry line.
+/// The date is only recognized when it is followed by whitespace and then a time range,
+/// so slashes inside project names or notes are left alone.
+fn split_date_prefix(line: &str) -> (Option<Date>, &str) {
+    let regex = DATE_PREFIX_REGEX.get_or_init(|| {
+        regex::Regex::new(r"^(\d{1,2}/\d{1,2}(?:/\d{4})?)\s+").expect("could not compile regex")
     });
 
-    if let Some(prefix) = prefix {
-        line.starts_with(prefix)
+    if let Some(captures) = regex.captures(line) {
+        let rest = &line[captures[0].len()..];
+        if starts_with_entry(rest)
+            && let Ok(date) = captures[1].parse()
+        {
+            return (Some(date), rest);
+        }
+    }
+
+    (None, line)
+}
+
+/// Split a leading tag like the initials in "SC 8-9 project" off an entry line.
+/// The tag must start with a letter and be followed by a (possibly dated) time range.
+fn split_leading_tag(line: &str) -> (Option<String>, &str) {
+    if let Some((tag, rest)) = line.split_once(char::is_whitespace) {
+        let rest = rest.trim_start();
+        if tag.starts_with(char::is_alphabetic) && starts_with_entry(split_date_prefix(rest).1) {
+            return (Some(tag.to_string()), rest);
+        }
+    }
+
+    (None, line)
+}
+
+/// Split the optional leading tag and date off an entry line, leaving the time range first
+fn split_entry_prefixes<'a>(
+    line: &'a str,
+    config: &ParserConfig,
+) -> (Option<String>, Option<Date>, &'a str) {
+    let (tag, line) = if config.leading_tag {
+        split_leading_tag(line)
+    } else {
+        (None, line)
+    };
+    let (date, line) = split_date_prefix(line);
+    (tag, date, line)
+}
+
+/// Check if a line looks like a time tracking entry (e.g., "10-2 project", "10:30-3 project"
+/// or "1h5m project"). This includes lines that have the time pattern but might be missing
+/// the project name
+fn is_time_tracking_line(line: &str, config: &ParserConfig) -> bool {
+    if let Some(prefix) = &config.prefix {
+        line.starts_with(prefix.as_str())
     } else {
-        regex.is_match(line)
+        starts_with_entry(split_entry_prefixes(line, config).2)
     }
 }
 
-/// Check if we should continue parsing (line starts with number, dash, or space)
-fn should_continue_parsing(line: &str, suffix: Option<&str>) -> bool {
+/// Check if a line is a bulleted note (e.g. "- did a thing" or "* did a thing")
+fn is_note_line(line: &str, note_prefixes: &[String]) -> bool {
+    note_prefixes
+        .iter()
+        .any(|marker| line.starts_with(marker.as_str()))
+}
+
+/// Nesting depth of a note from the indentation before it: one level per tab or per
+/// two spaces
+fn note_depth(indent: &str) -> usize {
+    let tabs = indent.chars().filter(|c| *c == '\t').count();
+    let spaces = indent.chars().filter(|c| *c == ' ').count();
+    tabs + spaces / 2
+}
+
+/// Check if we should continue parsing (i.e. the line isn't the configured suffix).
+/// Bulleted notes never trigger the stop unless the whole line is exactly the suffix,
+/// so a suffix like "-" doesn't cut off an entry's notes.
+fn should_continue_parsing(line: &str, suffix: Option<&str>, note_prefixes: &[String]) -> bool {
     if let Some(suffix) = suffix {
-        !line.starts_with(suffix)
+        line != suffix && (is_note_line(line, note_prefixes) || !line.starts_with(suffix))
     } else {
         true
     }
 }
 
-/// Main parsing function
-pub fn parse_time_tracking_data(
-    input: &str,
-    prefix: Option<&str>,
-    suffix: Option<&str>,
-) -> TimeTrackingData {
-    let mut data = TimeTrackingData::new();
-    let mut entries = Vec::new();
-    let mut current_entry: Option<TimeEntry> = None;
-    let mut parsing_started = false;
+/// Split an inline note off the project text, so "project: fixed the deploy script"
+/// becomes the project "project" with that note
+fn split_inline_note(text: &str) -> (String, Vec<String>) {
+    match text.split_once(": ") {
+        Some((project, note)) if !note.trim().is_empty() => {
+            (project.trim().to_string(), vec![note.trim().to_string()])
+        }
+        _ => (text.trim().to_string(), Vec::new()),
+    }
+}
 
-    for line in input.lines() {
+/// Split trailing tags off an entry's project, e.g. "api#backend#infra" becomes project
+/// "api" with tags ["backend", "infra"]. A project without a name before the first '#'
+/// or without any non-empty tag (like "C#") is left alone.
+fn split_project_tags(entry: TimeEntry) -> TimeEntry {
+    let Some((name, tags)) = entry.project.split_once('#') else {
+        return entry;
+    };
+    let tags: Vec<String> = tags
+        .split('#')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(str::to_string)
+        .collect();
+    if name.trim().is_empty() || tags.is_empty() {
+        return entry;
+    }
+    TimeEntry {
+        project: name.trim().to_string(),
+        tags,
+        ..entry
+    }
+}
+
+/// Check if a token looks like a billing code rather than a word of a note
+fn is_project_token(token: &str) -> bool {
+    token
+        .chars()
+        .all(|c| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '/' | '#'))
+}
+
+/// Split an entry whose project is several billing codes ("8-10 projA projB") into one
+/// entry per code, dividing the duration equally with any remainder going to the first.
+/// Clocked entries are split into consecutive ranges so the day's timeline is unchanged.
+fn split_across_projects(entry: TimeEntry) -> Vec<TimeEntry> {
+    let projects: Vec<&str> = entry.project.split_whitespace().collect();
+    if projects.len() < 2 || !projects.iter().all(|project| is_project_token(project)) {
+        return vec![entry];
+    }
+
+    let total = entry.duration_minutes();
+    let count = projects.len() as u32;
+    let mut start = entry.start;
+    projects
+        .iter()
+        .enumerate()
+        .map(|(index, project)| {
+            let minutes = total / count + if index == 0 { total % count } else { 0 };
+            let split = if entry.is_clocked() {
+                let end = start.add_minutes(minutes as i32);
+                let split = TimeEntry {
+                    start,
+                    end,
+                    ..entry.clone()
+                };
+                start = end;
+                split
+            } else {
+                TimeEntry {
+                    duration: Some(minutes),
+                    ..entry.clone()
+                }
+            };
+            TimeEntry {
+                project: project.to_string(),
+                ..split
+            }
+        })
+        .collect()
+}
+
+/// Split the project's tags off a newly parsed entry, first dividing it between several
+/// billing codes when `ParserConfig::split_projects` is set
+fn finish_entry(mut entry: TimeEntry, config: &ParserConfig) -> Vec<TimeEntry> {
+    mark_midnight_crossing(&mut entry);
+
+    if config.split_projects {
+        split_across_projects(entry)
+            .into_iter()
+            .map(split_project_tags)
+            .collect()
+    } else {
+        vec![split_project_tags(entry)]
+    }
+}
+
+/// Set `crosses_midnight` on an entry whose times both carry AM/PM and whose end is
+/// earlier in the day than its start
+fn mark_midnight_crossing(entry: &mut TimeEntry) {
+    entry.crosses_midnight = entry.is_clocked()
+        && matches!(
+            (entry.start.to_minutes_24(), entry.end.to_minutes_24()),
+            (Some(start), Some(end)) if end < start
+        );
+}
+
+/// Split the leading ranges off a line listing several, like "8-9, 2-3 review" or
+/// "8-9,2-3 review", returning the earlier ranges ("8-9") with their byte offsets in
+/// `line`, and the rest of the line starting at the last range ("2-3 review")
+fn split_range_list(line: &str) -> (Vec<(usize, &str)>, &str) {
+    let mut earlier = Vec::new();
+    let mut rest = line;
+    loop {
+        let (token, remainder) = rest
+            .split_once(char::is_whitespace)
+            .map_or((rest, ""), |(token, remainder)| {
+                (token, remainder.trim_start())
+            });
+        let Some((ranges, last)) = token.rsplit_once(',') else {
+            break;
+        };
+        if ranges.split(',').any(str::is_empty) {
+            break;
+        }
+        let with_offsets = ranges
+            .split(',')
+            .scan(line.len() - rest.len(), |offset, range| {
+                let start = *offset;
+                *offset += range.len() + 1;
+                Some((start, range))
+            });
+        if last.is_empty() && starts_with_entry(remainder) {
+            // "8-9, 2-3 review": the list continues after the whitespace
+            earlier.extend(with_offsets);
+            rest = remainder;
+        } else if starts_with_entry(last) {
+            // "8-9,2-3 review": the last range is inside this token
+            earlier.extend(with_offsets);
+            rest = &rest[ranges.len() + 1..];
+            break;
+        } else {
+            break;
+        }
+    }
+    (earlier, rest)
+}
+
+/// The lines of `input` like `str::lines`, each with the byte offset it starts at. When
+/// `input` is a slice of a larger document, `start` is its offset in the document.
+fn lines_with_offsets(input: &str, start: usize) -> impl Iterator<Item = (Option<usize>, &str)> {
+    let mut offset = start;
+    input.split_inclusive('\n').map(move |chunk| {
+        let start = offset;
+        offset += chunk.len();
+        let line = match chunk.strip_suffix('\n') {
+            Some(line) => line.strip_suffix('\r').unwrap_or(line),
+            None => chunk,
+        };
+        (Some(start), line)
+    })
+}
+
+/// State of the line-by-line parse loop, shared by the eager parsers and `entries_iter`
+#[derive(Default)]
+struct EntryParser {
+    current_entry: Option<TimeEntry>,
+    /// Whether `current_entry` is open-ended ("2- meeting") and ends at `ParserConfig::now`
+    open_ended: bool,
+    /// Span of the open-ended entry's range, for its warning
+    open_ended_span: Option<std::ops::Range<usize>>,
+    /// Entries split off an open-ended entry ahead of `current_entry`, held back until
+    /// it's known to be the last entry
+    open_ended_split: Vec<TimeEntry>,
+    parsing_started: bool,
+    stopped: bool,
+}
+
+impl EntryParser {
+    /// Feed one input line through the parser. Entries completed by this line, and lines
+    /// that couldn't be turned into an entry, are appended to `output` in order.
+    fn parse_line(
+        &mut self,
+        config: &ParserConfig,
+        line_number: usize,
+        line_offset: Option<usize>,
+        line: &str,
+        warnings: &mut Vec<ParseWarning>,
+        output: &mut Vec<Result<TimeEntry, ParseError>>,
+    ) {
+        let indent = &line[..line.len() - line.trim_start().len()];
         let line = line.trim();
-        if line.is_empty() {
-            continue;
+        if self.stopped || line.is_empty() {
+            return;
+        }
+
+        // Byte range of the whole (trimmed) line in the original input
+        let line_start = line_offset.map(|offset| offset + indent.len());
+        let line_span = line_start.map(|start| start..start + line.len());
+        if let Some(limit) = config.max_line_length
+            && line.len() > limit
+        {
+            warnings.push(ParseWarning::LineTooLong {
+                line_number,
+                span: line_span.clone(),
+                length: line.len(),
+                limit,
+            });
+            return;
         }
 
         // If we haven't started parsing yet, look for the first time tracking line
-        if !parsing_started {
-            if is_time_tracking_line(line, prefix) {
-                parsing_started = true;
-                if prefix.is_some() {
-                    continue; // Skip the prefix line
+        if !self.parsing_started {
+            if is_time_tracking_line(line, config) {
+                self.parsing_started = true;
+                if config.prefix.is_some() {
+                    return; // Skip the prefix line
                 }
             } else {
-                continue; // Skip lines until we find a time tracking pattern
+                return; // Skip lines until we find a time tracking pattern
             }
         }
 
         // If we've started parsing, check if we should continue
-        if parsing_started && !should_continue_parsing(line, suffix) {
-            break; // Stop parsing when we hit a line that doesn't start with number, dash, or space
+        if config.stop_mode != StopMode::Never
+            && !should_continue_parsing(line, config.suffix.as_deref(), &config.note_prefixes)
+        {
+            self.stopped = true; // Stop parsing when we hit the suffix
+            return;
+        }
+
+        // Comment lines are skipped without affecting the current entry
+        if let Some(comment_prefix) = &config.comment_prefix
+            && line.starts_with(comment_prefix.as_str())
+        {
+            return;
         }
 
-        if !line.starts_with(char::is_numeric) && !line.is_empty() {
-            if let Some(ref mut entry) = current_entry {
-                entry.notes.push(
-                    line.strip_prefix_sane("-")
-                        .strip_prefix_sane("*")
-                        .trim()
-                        .to_string(),
-                );
+        let original_line = line;
+        let (tag, date, line) = split_entry_prefixes(line, config);
+        let prefix_len = original_line.len() - line.len();
+        let (normalized, rewrite) = normalize_range_separator(line);
+        let line = normalized.as_ref();
+
+        // Byte range in the original input of the `len` bytes at `position` in `line`,
+        // mapped back through the prefixes and the separator rewrite
+        let span_at = |position: usize, len: usize| {
+            line_start.map(|start| {
+                let start = start + prefix_len;
+                start + rewrite.original_position(position)
+                    ..start + rewrite.original_position(position + len)
+            })
+        };
+
+        if !line.starts_with(char::is_numeric) && !starts_with_entry(line) && !line.is_empty() {
+            // Stray prose rather than a bulleted note
+            if !is_note_line(line, &config.note_prefixes) {
+                match config.stop_mode {
+                    StopMode::AtSuffix => {}
+                    StopMode::AtNonMatchingLine => {
+                        self.stopped = true;
+                        return;
+                    }
+                    StopMode::Never => {
+                        warnings.push(ParseWarning::UnrecognizedLine {
+                            line_number,
+                            span: line_span,
+                            line: original_line.to_string(),
+                        });
+                        return;
+                    }
+                }
             }
-        } else {
-            // Save previous entry if exists
-            if let Some(entry) = current_entry.take() {
-                entries.push(entry);
+            if let Some(ref mut entry) = self.current_entry {
+                let note = config
+                    .note_prefixes
+                    .iter()
+                    .fold(line, |note, marker| note.strip_prefix_sane(marker));
+                entry.add_note(note.trim().to_string(), note_depth(indent));
             }
+            return;
+        }
 
-            // Parse new time entry
-            let mut parts: Vec<&str> = line.splitn(2, ' ').collect();
-            if parts.len() < 2 {
-                data.warnings
-                    .push(format!("Line missing project name: {line}"));
-                parts.push("missing");
+        // Save previous entry if exists. Only the last entry may be open-ended.
+        if let Some(entry) = self.current_entry.take() {
+            if self.open_ended {
+                self.open_ended_split.clear();
+                warnings.push(ParseWarning::OpenEndedEntry {
+                    line_number: entry.line_number,
+                    span: self.open_ended_span.take(),
+                    start: entry.start,
+                });
+            } else {
+                output.push(Ok(entry));
             }
+        }
+        self.open_ended = false;
+
+        // Earlier ranges of a list like "8-9, 2-3 review" get entries of their own below;
+        // the last range is parsed as usual and collects the notes
+        let (earlier_ranges, line) = split_range_list(line);
+        let range_position = normalized.len() - line.len();
+
+        // Parse new time entry, split from its project at the first run of whitespace
+        // (spaces or tabs)
+        let mut parts: Vec<&str> = match line.split_once(char::is_whitespace) {
+            Some((range, project)) => vec![range, project.trim_start()],
+            None => vec![line],
+        };
+        if parts.len() < 2 {
+            warnings.push(ParseWarning::MissingProjectName {
+                line_number,
+                span: span_at(range_position, line.len()),
+                line: line.to_string(),
+            });
+            parts.push("missing");
+        }
 
-            match parse_time_range(parts[0]) {
+        let (project, notes) = split_inline_note(parts[1]);
+        for (position, range) in earlier_ranges {
+            match parse_time_range(range, config) {
                 Ok((start, end)) => {
-                    let project = parts[1].trim().to_string();
-                    current_entry = Some(TimeEntry {
+                    let entry = TimeEntry {
                         start,
                         end,
-                        project,
+                        project: project.clone(),
                         notes: Vec::new(),
+                        date,
+                        tag: tag.clone(),
+                        line_number,
+                        duration: None,
+                        tags: Vec::new(),
+                        note_depths: Vec::new(),
+                        crosses_midnight: false,
+                    };
+                    output.extend(finish_entry(entry, config).into_iter().map(Ok));
+                }
+                Err(e) => {
+                    output.push(Err(ParseError::new(line, e.clone())));
+                    warnings.push(ParseWarning::InvalidTimeRange {
+                        line_number,
+                        span: span_at(position, range.len()),
+                        raw: range.to_string(),
+                        reason: e,
+                    });
+                }
+            }
+        }
+
+        let entry = if let Some(duration) = parse_duration(parts[0]) {
+            match duration {
+                Ok(minutes) => TimeEntry {
+                    project,
+                    notes,
+                    date,
+                    tag,
+                    line_number,
+                    ..TimeEntry::from_duration(minutes)
+                },
+                Err(e) => {
+                    output.push(Err(ParseError::new(line, e.clone())));
+                    warnings.push(ParseWarning::InvalidTimeRange {
+                        line_number,
+                        span: span_at(range_position, parts[0].len()),
+                        raw: parts[0].to_string(),
+                        reason: e,
                     });
+                    return;
+                }
+            }
+        } else if let Some(start) = parts[0].strip_suffix('-') {
+            // An open-ended entry like "2- meeting" runs until `now`, if given
+            match parse_config_time(start, config) {
+                Ok(start) => {
+                    self.open_ended = true;
+                    self.open_ended_span = span_at(range_position, parts[0].len());
+                    let entry = TimeEntry {
+                        start,
+                        end: config.now.unwrap_or(start),
+                        project,
+                        notes,
+                        date,
+                        tag,
+                        line_number,
+                        duration: None,
+                        tags: Vec::new(),
+                        note_depths: Vec::new(),
+                        crosses_midnight: false,
+                    };
+                    let mut entries = finish_entry(entry, config);
+                    self.current_entry = entries.pop();
+                    self.open_ended_split = entries;
                 }
                 Err(e) => {
-                    data.warnings
-                        .push(format!("Error parsing time range '{}': {}", parts[0], e));
+                    output.push(Err(ParseError::new(line, e.clone())));
+                    warnings.push(ParseWarning::InvalidTimeRange {
+                        line_number,
+                        span: span_at(range_position, parts[0].len()),
+                        raw: parts[0].to_string(),
+                        reason: e,
+                    });
                 }
             }
+            return;
+        } else {
+            match parse_time_range(parts[0], config) {
+                Ok((start, end)) => TimeEntry {
+                    start,
+                    end,
+                    project,
+                    notes,
+                    date,
+                    tag,
+                    line_number,
+                    duration: None,
+                    tags: Vec::new(),
+                    note_depths: Vec::new(),
+                    crosses_midnight: false,
+                },
+                Err(e) => {
+                    output.push(Err(ParseError::new(line, e.clone())));
+                    warnings.push(ParseWarning::InvalidTimeRange {
+                        line_number,
+                        span: span_at(range_position, parts[0].len()),
+                        raw: parts[0].to_string(),
+                        reason: e,
+                    });
+                    return;
+                }
+            }
+        };
+
+        let mut entries = finish_entry(entry, config);
+        self.current_entry = entries.pop();
+        output.extend(entries.into_iter().map(Ok));
+    }
+
+    /// Finish parsing, returning the entry still collecting notes, if any, after any
+    /// entries split off ahead of it. A final open-ended entry is only kept when
+    /// `ParserConfig::now` gives it an end.
+    fn finish(
+        &mut self,
+        config: &ParserConfig,
+        warnings: &mut Vec<ParseWarning>,
+    ) -> Vec<TimeEntry> {
+        self.stopped = true;
+        let mut entries = std::mem::take(&mut self.open_ended_split);
+        let Some(entry) = self.current_entry.take() else {
+            return entries;
+        };
+        if self.open_ended && config.now.is_none() {
+            warnings.push(ParseWarning::OpenEndedEntry {
+                line_number: entry.line_number,
+                span: self.open_ended_span.take(),
+                start: entry.start,
+            });
+            return Vec::new();
+        }
+        entries.push(entry);
+        entries
+    }
+}
+
+/// Run the line-by-line parse loop, collecting entries and any parse warnings.
+/// Lines that couldn't be turned into an entry are also recorded in `failures`.
+/// `first_line` is the 1-based line number of the first line.
+fn parse_entries<S: AsRef<str>>(
+    lines: impl IntoIterator<Item = (Option<usize>, S)>,
+    first_line: usize,
+    config: &ParserConfig,
+    warnings: &mut Vec<ParseWarning>,
+    failures: &mut Vec<ParseError>,
+) -> Vec<TimeEntry> {
+    let mut parser = EntryParser::default();
+    let mut output = Vec::new();
+
+    for (index, (offset, line)) in lines.into_iter().enumerate() {
+        if parser.stopped {
+            break;
+        }
+        parser.parse_line(
+            config,
+            first_line + index,
+            offset,
+            line.as_ref(),
+            warnings,
+            &mut output,
+        );
+    }
+
+    let mut entries = Vec::with_capacity(output.len() + 1);
+    for result in output {
+        match result {
+            Ok(entry) => entries.push(entry),
+            Err(failure) => failures.push(failure),
         }
     }
 
     // Don't forget the last entry
-    if let Some(entry) = current_entry {
-        entries.push(entry);
+    entries.extend(parser.finish(config, warnings));
+    entries
+}
+
+/// Lazily yields entries (with their notes) as input lines are consumed, skipping
+/// aggregation and validation. Lines with an invalid time range yield an error.
+pub struct EntriesIter<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    config: ParserConfig,
+    parser: EntryParser,
+    pending: std::collections::VecDeque<Result<TimeEntry, ParseError>>,
+    scratch: Vec<Result<TimeEntry, ParseError>>,
+    warnings: Vec<ParseWarning>,
+}
+
+impl Iterator for EntriesIter<'_> {
+    type Item = Result<TimeEntry, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(result) = self.pending.pop_front() {
+                return Some(result);
+            }
+            let next_line = if self.parser.stopped {
+                None
+            } else {
+                self.lines.next()
+            };
+            let Some((index, line)) = next_line else {
+                let finished = self.parser.finish(&self.config, &mut self.warnings);
+                self.pending.extend(finished.into_iter().map(Ok));
+                return self.pending.pop_front();
+            };
+            self.parser.parse_line(
+                &self.config,
+                index + 1,
+                None,
+                line,
+                &mut self.warnings,
+                &mut self.scratch,
+            );
+            // Warnings are only collected by the full parser
+            self.warnings.clear();
+            self.pending.extend(self.scratch.drain(..));
+        }
+    }
+}
+
+/// Iterate over the entries in `input` as they're parsed, without building project
+/// summaries or warnings
+pub fn entries_iter<'a>(
+    input: &'a str,
+    prefix: Option<&str>,
+    suffix: Option<&str>,
+) -> EntriesIter<'a> {
+    EntriesIter {
+        lines: input.lines().enumerate(),
+        config: ParserConfig {
+            prefix: prefix.map(str::to_string),
+            suffix: suffix.map(str::to_string),
+            ..Default::default()
+        },
+        parser: EntryParser::default(),
+        pending: Default::default(),
+        scratch: Vec::new(),
+        warnings: Vec::new(),
+    }
+}
+
+/// Parse a single entry line like "8:30-10 project" into an entry, with the default
+/// config. The line is read exactly as `EntryParser` reads entry lines in a full parse,
+/// so "7–8 project", "7 to 8 project" and durations like "1h30m review" all work, and
+/// inline notes and tags are split off the project. No note lines are collected. The
+/// entry is reported as line 1.
+pub fn parse_entry_line(line: &str) -> Result<TimeEntry, ParseError> {
+    let line = line.trim();
+    let config = ParserConfig::default();
+    let mut parser = EntryParser::default();
+    let mut warnings = Vec::new();
+    let mut output = Vec::new();
+    parser.parse_line(&config, 1, None, line, &mut warnings, &mut output);
+    output.extend(parser.finish(&config, &mut warnings).into_iter().map(Ok));
+
+    let mut entries = Vec::with_capacity(output.len());
+    for result in output {
+        entries.push(result?);
+    }
+    if let Some(warning) = warnings.first() {
+        let reason = match warning {
+            ParseWarning::MissingProjectName { .. } => "Line missing project name".to_string(),
+            warning => warning.to_string(),
+        };
+        return Err(ParseError::new(line, reason));
+    }
+    match <[TimeEntry; 1]>::try_from(entries) {
+        Ok([entry]) => Ok(entry),
+        Err(entries) if entries.is_empty() => Err(ParseError::new(line, "Not an entry line")),
+        Err(_) => Err(ParseError::new(line, "Line has more than one entry")),
     }
+}
+
+/// Main parsing function
+pub fn parse_time_tracking_data(
+    input: &str,
+    prefix: Option<&str>,
+    suffix: Option<&str>,
+) -> TimeTrackingData {
+    let config = ParserConfig {
+        prefix: prefix.map(str::to_string),
+        suffix: suffix.map(str::to_string),
+        ..Default::default()
+    };
+    parse_with_config(input, &config)
+}
+
+/// Strict variant of `parse_time_tracking_data` that fails when no valid entries are found.
+/// The error points at the first line that couldn't be parsed, if any.
+pub fn try_parse_time_tracking_data(
+    input: &str,
+    prefix: Option<&str>,
+    suffix: Option<&str>,
+) -> Result<TimeTrackingData, ParseError> {
+    let config = ParserConfig {
+        prefix: prefix.map(str::to_string),
+        suffix: suffix.map(str::to_string),
+        ..Default::default()
+    };
+    try_parse_with_config(input, &config)
+}
+
+/// Strict variant of `parse_with_config` that fails when no valid entries are found
+pub fn try_parse_with_config(
+    input: &str,
+    config: &ParserConfig,
+) -> Result<TimeTrackingData, ParseError> {
+    let mut data = TimeTrackingData::new();
+    let mut failures = Vec::new();
+    let entries = parse_entries(
+        lines_with_offsets(input, 0),
+        1,
+        config,
+        &mut data.warnings,
+        &mut failures,
+    );
+    if entries.is_empty() {
+        return Err(failures
+            .into_iter()
+            .next()
+            .unwrap_or_else(|| ParseError::new("", "No time entries found")));
+    }
+
+    Ok(summarize_entries(data, entries, config))
+}
+
+/// Parse time tracking data using the options in `config`
+pub fn parse_with_config(input: &str, config: &ParserConfig) -> TimeTrackingData {
+    parse_section(input, 1, 0, config)
+}
+
+/// Parse a slice of a larger document that starts on line `first_line` at byte offset
+/// `start`, so warnings point into the whole document
+fn parse_section(
+    input: &str,
+    first_line: usize,
+    start: usize,
+    config: &ParserConfig,
+) -> TimeTrackingData {
+    let mut data = TimeTrackingData::new();
+    let entries = parse_entries(
+        lines_with_offsets(input, start),
+        first_line,
+        config,
+        &mut data.warnings,
+        &mut Vec::new(),
+    );
+    summarize_entries(data, entries, config)
+}
+
+/// Parse time tracking data line by line from a reader, without loading it all into
+/// memory first. Produces the same result as `parse_time_tracking_data` on the same content.
+pub fn parse_from_reader<R: BufRead>(
+    reader: R,
+    prefix: Option<&str>,
+    suffix: Option<&str>,
+) -> io::Result<TimeTrackingData> {
+    let config = ParserConfig {
+        prefix: prefix.map(str::to_string),
+        suffix: suffix.map(str::to_string),
+        ..Default::default()
+    };
+    parse_from_reader_with_config(reader, &config)
+}
+
+/// Parse time tracking data from a reader using the options in `config`
+pub fn parse_from_reader_with_config<R: BufRead>(
+    reader: R,
+    config: &ParserConfig,
+) -> io::Result<TimeTrackingData> {
+    let mut data = TimeTrackingData::new();
+    let mut read_error = None;
+    // Split on '\n' by hand rather than with `lines()` so each line's byte offset is known
+    let mut offset = 0;
+    let lines = reader
+        .split(b'\n')
+        .map(|chunk| {
+            let mut chunk = chunk?;
+            let start = offset;
+            offset += chunk.len() + 1;
+            if chunk.last() == Some(&b'\r') {
+                chunk.pop();
+            }
+            String::from_utf8(chunk)
+                .map(|line| (Some(start), line))
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+        })
+        .map_while(|line| line.map_err(|error| read_error = Some(error)).ok());
+    let entries = parse_entries(lines, 1, config, &mut data.warnings, &mut Vec::new());
+    if let Some(error) = read_error {
+        return Err(error);
+    }
+    Ok(summarize_entries(data, entries, config))
+}
+
+/// An entry's project as used for grouping, normalized when configured
+fn project_key(project: &str, config: &ParserConfig) -> String {
+    match config.project_normalization {
+        Some(normalization) => normalization.normalize(project),
+        None => project.to_string(),
+    }
+}
+
+/// Whether `entry` starts a new session, i.e. doesn't directly continue the same project
+/// as the entry before it
+fn starts_session(entries: &[TimeEntry], index: usize, config: &ParserConfig) -> bool {
+    let entry = &entries[index];
+    let Some(previous) = index.checked_sub(1).map(|previous| &entries[previous]) else {
+        return true;
+    };
+    !(project_key(&previous.project, config) == project_key(&entry.project, config)
+        && previous.is_clocked()
+        && entry.is_clocked()
+        && previous.end.gap(&entry.start) == 0)
+}
+
+/// Fold one entry into its project's summary
+fn add_to_summary(
+    summary: &mut ProjectSummary,
+    entry: &TimeEntry,
+    minutes: u32,
+    new_session: bool,
+    config: &ParserConfig,
+) {
+    summary.add_time(minutes);
+    summary.add_tags(&entry.tags);
+    if config.dedup_notes {
+        summary.add_unique_notes(entry.notes.clone());
+    } else {
+        summary.add_notes(entry.notes.clone());
+    }
+    if new_session {
+        summary.add_session();
+    }
+}
+
+/// Per-project summaries keyed by `project_key`, each stored with the index of the entry
+/// its project first appeared in. Entries without a project name are skipped.
+fn aggregate_projects(
+    entries: &[TimeEntry],
+    config: &ParserConfig,
+    entry_minutes: &impl Fn(&TimeEntry) -> u32,
+) -> HashMap<String, (usize, ProjectSummary)> {
+    let mut project_map: HashMap<String, (usize, ProjectSummary)> = HashMap::new();
+    for (index, entry) in entries.iter().enumerate() {
+        if entry.project.is_empty() {
+            continue;
+        }
+
+        let (_, summary) = project_map
+            .entry(project_key(&entry.project, config))
+            .or_insert_with(|| (index, ProjectSummary::new(entry.project.clone())));
+        let new_session = starts_session(entries, index, config);
+        add_to_summary(summary, entry, entry_minutes(entry), new_session, config);
+    }
+    project_map
+}
+
+/// `aggregate_projects` spread across threads: each thread folds a run of entries into
+/// its own map, and the maps are merged in entry order so the result (including note
+/// and tag order) matches the sequential version exactly
+#[cfg(feature = "parallel")]
+fn aggregate_projects_parallel(
+    entries: &[TimeEntry],
+    config: &ParserConfig,
+    entry_minutes: &(impl Fn(&TimeEntry) -> u32 + Sync),
+) -> HashMap<String, (usize, ProjectSummary)> {
+    use rayon::prelude::*;
+    use std::collections::hash_map::Entry;
+
+    entries
+        .par_iter()
+        .enumerate()
+        .fold(
+            HashMap::new,
+            |mut project_map: HashMap<String, (usize, ProjectSummary)>, (index, entry)| {
+                if entry.project.is_empty() {
+                    return project_map;
+                }
+                let (_, summary) = project_map
+                    .entry(project_key(&entry.project, config))
+                    .or_insert_with(|| (index, ProjectSummary::new(entry.project.clone())));
+                let new_session = starts_session(entries, index, config);
+                add_to_summary(summary, entry, entry_minutes(entry), new_session, config);
+                project_map
+            },
+        )
+        .reduce(HashMap::new, |mut earlier, later| {
+            for (key, (index, summary)) in later {
+                match earlier.entry(key) {
+                    Entry::Occupied(mut existing) => {
+                        let (_, existing) = existing.get_mut();
+                        existing.add_time(summary.total_minutes);
+                        existing.add_tags(&summary.tags);
+                        if config.dedup_notes {
+                            existing.add_unique_notes(summary.notes);
+                        } else {
+                            existing.add_notes(summary.notes);
+                        }
+                        existing.session_count += summary.session_count;
+                    }
+                    Entry::Vacant(vacant) => {
+                        vacant.insert((index, summary));
+                    }
+                }
+            }
+            earlier
+        })
+}
+
+/// Give every clocked time without AM/PM one, assuming the day starts in `day_start` and
+/// only moves forward: each time reading earlier on the clock than the one before it
+/// moves to the other half of the day. Times with an explicit AM/PM are kept and
+/// carry their half of the day forward.
+fn resolve_meridiems(entries: &mut [TimeEntry], day_start: Meridiem) {
+    let mut current = day_start;
+    let mut previous: Option<Time> = None;
+    for entry in entries.iter_mut().filter(|entry| entry.is_clocked()) {
+        for time in [&mut entry.start, &mut entry.end] {
+            match time.meridiem {
+                Some(meridiem) => current = meridiem,
+                None => {
+                    if previous.is_some_and(|previous| time.to_minutes() < previous.to_minutes()) {
+                        current = current.opposite();
+                    }
+                    *time = time.with_meridiem(current);
+                }
+            }
+            previous = Some(*time);
+        }
+    }
+}
+
+/// An entry's billable minutes, rounded per block when configured
+fn billable_minutes(entry: &TimeEntry, config: &ParserConfig) -> u32 {
+    match config.round_entries_to {
+        Some(increment) => Time::round_minutes(entry.duration_minutes(), increment),
+        None => entry.duration_minutes(),
+    }
+}
+
+/// Give entries that run past midnight an evening start and a morning end, where AM/PM
+/// wasn't written. A day opening with a wrapping entry ("10-6") is a night shift. After
+/// that the timeline follows the same gap logic as the dead time calculation, so "11-1"
+/// after "9-11" is still a noon crossing while a "11-1" fifteen hours into the day
+/// crosses midnight.
+fn resolve_overnight(entries: &mut [TimeEntry]) {
+    let mut previous: Option<(Time, u32)> = None;
+    for entry in entries.iter_mut().filter(|entry| entry.is_clocked()) {
+        if previous.is_none() && is_ambiguous_crossing(&entry.start, &entry.end) {
+            entry.start = entry.start.with_default_meridiem(Meridiem::Pm);
+            entry.end = entry.end.with_default_meridiem(Meridiem::Am);
+        }
+        let start = match previous {
+            Some((end, position)) => position + end.gap(&entry.start),
+            None => minutes_into_day(&entry.start),
+        };
+        let end = start + entry.duration_minutes();
+        if start / (24 * 60) < end / (24 * 60) && !end.is_multiple_of(24 * 60) {
+            entry.start = entry.start.with_default_meridiem(Meridiem::Pm);
+            entry.end = entry.end.with_default_meridiem(Meridiem::Am);
+        }
+        previous = Some((entry.end, end));
+    }
+}
+
+/// Resolve AM/PM along the day when configured, then collect the validation warnings
+/// for the parsed entries. Shared by full parses and `validate_only`, so both report the
+/// same warnings.
+fn validate_day(data: &mut TimeTrackingData, entries: &mut [TimeEntry], config: &ParserConfig) {
+    if let Some(day_start) = config.day_start_meridiem {
+        resolve_meridiems(entries, day_start);
+    }
+    if config.overnight {
+        resolve_overnight(entries);
+    }
+    entries.iter_mut().for_each(mark_midnight_crossing);
 
     // Check for potential time order issues (duration > 6 hours or large gaps)
-    data.validate_entries(&entries);
+    data.validate_entries(entries, &config.validation);
 
-    // Calculate overall start and end times using all entries
-    if !entries.is_empty() {
-        data.start_time = Some(entries.first().unwrap().start);
-        data.end_time = Some(entries.last().unwrap().end);
+    // A total over a full day usually means more than one day was pasted in
+    let mut running_minutes = 0;
+    if let Some(entry) = entries.iter().find(|entry| {
+        running_minutes += billable_minutes(entry, config);
+        running_minutes > 24 * 60
+    }) {
+        let total_minutes = entries
+            .iter()
+            .map(|entry| billable_minutes(entry, config))
+            .sum();
+        data.validation_warnings
+            .push(ParseWarning::TotalExceedsDay {
+                line_number: entry.line_number,
+                total_minutes,
+            });
     }
+}
+
+/// Validate parsed entries and compute totals, dead time and per-project summaries
+fn summarize_entries(
+    mut data: TimeTrackingData,
+    mut entries: Vec<TimeEntry>,
+    config: &ParserConfig,
+) -> TimeTrackingData {
+    validate_day(&mut data, &mut entries, config);
+    let entry_minutes = |entry: &TimeEntry| billable_minutes(entry, config);
+
+    // Only entries with clock times place the day on the clock; duration-only entries
+    // count toward the totals but not the start/end or dead time
+    let clocked: Vec<&TimeEntry> = entries.iter().filter(|entry| entry.is_clocked()).collect();
+
+    // Calculate overall start and end times using all clocked entries
+    if !clocked.is_empty() {
+        data.start_time = Some(clocked.first().unwrap().start);
+        data.end_time = Some(clocked.last().unwrap().end);
+    }
+    data.date = entries.iter().find_map(|entry| entry.date);
 
     // Calculate total working time using all entries (including ones without project names)
     let mut total_minutes = 0;
     for entry in &entries {
-        total_minutes += entry.duration_minutes();
+        total_minutes += entry_minutes(entry);
     }
 
-    // Calculate dead time using all entries (reuse the gap calculation)
-    entries.windows(2).for_each(|chunk| {
+    // Calculate dead time using all clocked entries (reuse the gap calculation), leaving
+    // out any configured lunch break
+    clocked.windows(2).for_each(|chunk| {
         if let [first, second] = chunk {
-            let gap = first.end.gap(&second.start);
+            let gap = first.end.gap(&second.start)
+                - config.validation.break_minutes(&first.end, &second.start);
             if gap > 0 {
                 data.dead_time_minutes += gap;
             }
@@ -164,24 +1299,23 @@ pub fn parse_time_tracking_data(
     data.total_minutes = total_minutes;
 
     // Aggregate by project using only entries with valid project names
-    let mut project_map: HashMap<String, ProjectSummary> = HashMap::new();
-
-    for entry in &entries {
-        // Skip entries without project names for project aggregation
-        if entry.project.is_empty() {
-            continue;
-        }
-
-        let project_summary = project_map
-            .entry(entry.project.clone())
-            .or_insert_with(|| ProjectSummary::new(entry.project.clone()));
+    #[cfg(feature = "parallel")]
+    let project_map = if config.parallel {
+        aggregate_projects_parallel(&entries, config, &entry_minutes)
+    } else {
+        aggregate_projects(&entries, config, &entry_minutes)
+    };
+    // Without the feature the `parallel` option has nothing to switch to
+    #[cfg(not(feature = "parallel"))]
+    let project_map = aggregate_projects(&entries, config, &entry_minutes);
 
-        project_summary.add_time(entry.duration_minutes());
-        project_summary.add_notes(entry.notes.clone());
+    let mut projects: Vec<(usize, ProjectSummary)> = project_map.into_values().collect();
+    match config.project_order {
+        ProjectOrder::Alphabetical => projects.sort_by(|a, b| a.1.name.cmp(&b.1.name)),
+        ProjectOrder::FirstSeen => projects.sort_by_key(|(first_seen, _)| *first_seen),
     }
-
-    data.projects = project_map.into_values().collect();
-    data.projects.sort_by(|a, b| a.name.cmp(&b.name));
+    data.projects = projects.into_iter().map(|(_, project)| project).collect();
+    data.entries = entries;
 
     data
 }
@@ -201,3 +1335,77 @@ pub fn parse_time_data_to_json_pretty(
     data.to_json_pretty()
         .unwrap_or_else(|e| format!("Error serializing to JSON: {e}"))
 }
+
+/// Like `parse_time_data_to_json`, but with camelCase keys (see
+/// `TimeTrackingData::to_json_camel`)
+pub fn parse_time_data_to_json_camel(
+    input: &str,
+    prefix: Option<&str>,
+    suffix: Option<&str>,
+) -> String {
+    let data = parse_time_tracking_data(input, prefix, suffix);
+    data.to_json_camel()
+        .unwrap_or_else(|e| format!("Error serializing to JSON: {e}"))
+}
+
+/// Parse a log covering several days, where a line holding just an ISO date like
+/// "2024-03-04" starts each day's section. Entries before the first header are returned
+/// with no date. See `parse_multi_day_with_config`.
+pub fn parse_multi_day(input: &str) -> Vec<(Option<Date>, TimeTrackingData)> {
+    parse_multi_day_with_config(input, &ParserConfig::default())
+}
+
+/// Parse a multi-day log, summarizing each date section separately using the options in
+/// `config`. Entries without their own date prefix take the section's date, and line
+/// numbers still refer to the whole input.
+pub fn parse_multi_day_with_config(
+    input: &str,
+    config: &ParserConfig,
+) -> Vec<(Option<Date>, TimeTrackingData)> {
+    // Each section is a slice of the input, parsed with its first line number and byte
+    // offset so warnings match the original input
+    let mut sections: Vec<(Option<Date>, usize, Range<usize>)> = vec![(None, 1, 0..0)];
+    let mut offset = 0;
+    for (index, chunk) in input.split_inclusive('\n').enumerate() {
+        let end = offset + chunk.len();
+        match Date::from_iso(chunk.trim()) {
+            Ok(date) => sections.push((Some(date), index + 2, end..end)),
+            Err(_) => sections.last_mut().unwrap().2.end = end,
+        }
+        offset = end;
+    }
+
+    sections
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, (date, first_line, range))| {
+            let start = range.start;
+            let mut data = parse_section(&input[range], first_line, start, config);
+            if index == 0 && data.entries.is_empty() && data.all_warnings().is_empty() {
+                return None;
+            }
+            if let Some(date) = date {
+                data.date = Some(date);
+                for entry in &mut data.entries {
+                    entry.date.get_or_insert(date);
+                }
+            }
+            Some((date, data))
+        })
+        .collect()
+}
+
+/// Check whether input is well-formed without aggregating it.
+/// Runs the parse loop and entry validation only, returning the same warnings as a full parse.
+pub fn validate_only(input: &str, config: &ParserConfig) -> Vec<ParseWarning> {
+    let mut data = TimeTrackingData::new();
+    let mut entries = parse_entries(
+        lines_with_offsets(input, 0),
+        1,
+        config,
+        &mut data.warnings,
+        &mut Vec::new(),
+    );
+    validate_day(&mut data, &mut entries, config);
+    data.all_warnings()
+}
@@ -0,0 +1,247 @@
+use super::*;
+
+/// Options controlling how time tracking input is parsed
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParserConfig {
+    /// Line marking the start of the time tracking section (e.g. "```timetracking")
+    pub prefix: Option<String>,
+    /// Line marking the end of the time tracking section (e.g. "```")
+    pub suffix: Option<String>,
+    /// Read times on a 24-hour clock ("13:30-15:00"), so no noon-crossing guesses are made
+    pub twenty_four_hour: bool,
+    /// Round each entry's duration to the nearest multiple of this many minutes before
+    /// it's added to the totals (e.g. 6 for tenths of an hour)
+    pub round_entries_to: Option<u32>,
+    /// Treat a leading non-time token as a tag on the entry (e.g. "SC 8-9 project")
+    pub leading_tag: bool,
+    /// Thresholds for the long entry and large gap warnings
+    pub validation: ValidationConfig,
+    /// Markers that start a note line and are stripped from the note (e.g. "•" or ">")
+    pub note_prefixes: Vec<String>,
+    /// Skip (with a warning) any line longer than this many bytes, guarding against
+    /// pathological pasted input before any pattern matching runs
+    pub max_line_length: Option<usize>,
+    /// Split an entry naming several billing codes ("8-10 projA projB") evenly between them
+    pub split_projects: bool,
+    /// Normalize project names before aggregating, so "Admin" and "admin" share one
+    /// summary (displayed with the first-seen spelling)
+    pub project_normalization: Option<ProjectNormalization>,
+    /// Keep only the first occurrence of identical notes within a project
+    pub dedup_notes: bool,
+    /// The current time, used as the end of an open-ended last entry ("2- meeting")
+    pub now: Option<Time>,
+    /// When to stop parsing; see `StopMode`
+    pub stop_mode: StopMode,
+    /// Lines starting with this (e.g. "# lunch was here") are skipped entirely, neither
+    /// becoming notes nor stopping parsing. Only whole lines are comments, so project
+    /// tags like "api#backend" are unaffected.
+    pub comment_prefix: Option<String>,
+    /// Order of the project summaries in the parsed data
+    pub project_order: ProjectOrder,
+    /// Whether the day starts in the morning or afternoon. When set, times without AM/PM
+    /// are resolved along the day (rolling over at each noon or midnight), so "11-1" is
+    /// 11am to 1pm rather than a guessed noon crossing.
+    pub day_start_meridiem: Option<Meridiem>,
+    /// Fill in AM/PM for an entry that runs past midnight, so a day opening with "10-6"
+    /// is a 10pm to 6am night shift and a late "11-1" after a full day runs 11pm to 1am.
+    /// Such entries get `TimeEntry::crosses_midnight` set. A later wrap within the first
+    /// 12 hours of the day (e.g. "9-11" then "11-1") stays a noon crossing.
+    pub overnight: bool,
+    /// Aggregate projects across threads, for logs with tens of thousands of entries.
+    /// The result is identical to the sequential aggregation. Ignored unless the
+    /// `parallel` feature is enabled.
+    pub parallel: bool,
+}
+
+impl ParserConfig {
+    /// Default options, to be adjusted with the builder methods, e.g.
+    /// `ParserConfig::new().prefix("```timetracking").suffix("```").max_gap_minutes(8 * 60)`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    pub fn twenty_four_hour(mut self, twenty_four_hour: bool) -> Self {
+        self.twenty_four_hour = twenty_four_hour;
+        self
+    }
+
+    pub fn round_entries_to(mut self, increment: u32) -> Self {
+        self.round_entries_to = Some(increment);
+        self
+    }
+
+    pub fn leading_tag(mut self, leading_tag: bool) -> Self {
+        self.leading_tag = leading_tag;
+        self
+    }
+
+    pub fn validation(mut self, validation: ValidationConfig) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    pub fn max_entry_minutes(mut self, minutes: u32) -> Self {
+        self.validation.max_entry_minutes = minutes;
+        self
+    }
+
+    pub fn max_gap_minutes(mut self, minutes: u32) -> Self {
+        self.validation.max_gap_minutes = minutes;
+        self
+    }
+
+    pub fn lunch(mut self, start: Time, end: Time) -> Self {
+        self.validation.lunch = Some((start, end));
+        self
+    }
+
+    pub fn strict_meridiem(mut self, strict: bool) -> Self {
+        self.validation.strict_meridiem = strict;
+        self
+    }
+
+    pub fn note_prefixes<S: Into<String>>(mut self, prefixes: impl IntoIterator<Item = S>) -> Self {
+        self.note_prefixes = prefixes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn max_line_length(mut self, length: usize) -> Self {
+        self.max_line_length = Some(length);
+        self
+    }
+
+    pub fn split_projects(mut self, split_projects: bool) -> Self {
+        self.split_projects = split_projects;
+        self
+    }
+
+    pub fn project_normalization(mut self, normalization: ProjectNormalization) -> Self {
+        self.project_normalization = Some(normalization);
+        self
+    }
+
+    pub fn dedup_notes(mut self, dedup_notes: bool) -> Self {
+        self.dedup_notes = dedup_notes;
+        self
+    }
+
+    pub fn now(mut self, now: Time) -> Self {
+        self.now = Some(now);
+        self
+    }
+
+    pub fn stop_mode(mut self, stop_mode: StopMode) -> Self {
+        self.stop_mode = stop_mode;
+        self
+    }
+
+    /// Set the comment prefix, or disable comments with `None`
+    pub fn comment_prefix(mut self, comment_prefix: Option<&str>) -> Self {
+        self.comment_prefix = comment_prefix.map(str::to_string);
+        self
+    }
+
+    pub fn project_order(mut self, project_order: ProjectOrder) -> Self {
+        self.project_order = project_order;
+        self
+    }
+
+    pub fn day_start_meridiem(mut self, day_start_meridiem: Meridiem) -> Self {
+        self.day_start_meridiem = Some(day_start_meridiem);
+        self
+    }
+
+    pub fn overnight(mut self, overnight: bool) -> Self {
+        self.overnight = overnight;
+        self
+    }
+
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+}
+
+/// How project names are normalized when grouping entries into project summaries
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProjectNormalization {
+    /// Compare names ignoring case
+    Lowercase,
+    /// Compare names ignoring case, surrounding whitespace and repeated inner whitespace
+    TrimLowercase,
+}
+
+impl ProjectNormalization {
+    /// The key a project name is grouped under
+    pub fn normalize(&self, name: &str) -> String {
+        match self {
+            ProjectNormalization::Lowercase => name.to_lowercase(),
+            ProjectNormalization::TrimLowercase => name
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase(),
+        }
+    }
+}
+
+/// When parsing stops once it has started. In every mode, bulleted notes and entry
+/// lines are parsed as usual; the modes differ in how the suffix line and stray prose
+/// (lines that are neither an entry nor a bulleted note) are handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StopMode {
+    /// Stop at the configured suffix line. Stray prose is kept as a note on the
+    /// current entry. This is the long-standing behavior.
+    #[default]
+    AtSuffix,
+    /// Stop at the suffix line or at the first line of stray prose
+    AtNonMatchingLine,
+    /// Never stop, even at the suffix line. Stray prose is skipped with an
+    /// `UnrecognizedLine` warning.
+    Never,
+}
+
+/// Order of the project summaries in `TimeTrackingData::projects`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProjectOrder {
+    /// Sorted by project name
+    #[default]
+    Alphabetical,
+    /// In the order each project first appears in the log
+    FirstSeen,
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        Self {
+            prefix: None,
+            suffix: None,
+            twenty_four_hour: false,
+            round_entries_to: None,
+            leading_tag: false,
+            validation: ValidationConfig::default(),
+            note_prefixes: vec!["-".to_string(), "*".to_string()],
+            max_line_length: None,
+            split_projects: false,
+            project_normalization: None,
+            dedup_notes: false,
+            now: None,
+            stop_mode: StopMode::default(),
+            comment_prefix: Some("#".to_string()),
+            project_order: ProjectOrder::default(),
+            day_start_meridiem: None,
+            overnight: false,
+            parallel: false,
+        }
+    }
+}
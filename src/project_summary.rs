@@ -2,10 +2,17 @@ use super::*;
 
 /// Represents aggregated project data
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct ProjectSummary {
     pub name: String,
     pub total_minutes: u32,
     pub notes: Vec<String>,
+    /// Number of separate sessions (contiguous runs of entries) spent on this project
+    #[serde(default)]
+    pub session_count: u32,
+    /// Union of the tags seen on this project's entries, in first-seen order
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl ProjectSummary {
@@ -14,6 +21,8 @@ impl ProjectSummary {
             name,
             total_minutes: 0,
             notes: Vec::new(),
+            session_count: 0,
+            tags: Vec::new(),
         }
     }
 
@@ -21,7 +30,55 @@ impl ProjectSummary {
         self.total_minutes += minutes;
     }
 
+    pub fn add_session(&mut self) {
+        self.session_count += 1;
+    }
+
     pub fn add_notes(&mut self, notes: Vec<String>) {
         self.notes.extend(notes);
     }
+
+    /// Add notes, skipping any this project already has (first occurrences keep their order)
+    pub fn add_unique_notes(&mut self, notes: Vec<String>) {
+        for note in notes {
+            if !self.notes.contains(&note) {
+                self.notes.push(note);
+            }
+        }
+    }
+
+    /// Add tags, skipping any this project already has
+    pub fn add_tags(&mut self, tags: &[String]) {
+        for tag in tags {
+            if !self.tags.contains(tag) {
+                self.tags.push(tag.clone());
+            }
+        }
+    }
+
+    /// This project's share of `total_minutes` as an unrounded percentage (0.0-100.0).
+    /// A zero total gives 0.0 rather than NaN.
+    pub fn percent_of(&self, total_minutes: u32) -> f32 {
+        if total_minutes == 0 {
+            return 0.0;
+        }
+        (self.total_minutes as f32 * 100.0) / total_minutes as f32
+    }
+
+    pub fn note_count(&self) -> usize {
+        self.notes.len()
+    }
+
+    pub fn first_note(&self) -> Option<&str> {
+        self.notes.first().map(String::as_str)
+    }
+
+    pub fn last_note(&self) -> Option<&str> {
+        self.notes.last().map(String::as_str)
+    }
+
+    /// All notes folded into a single string, separated by `sep`
+    pub fn notes_joined(&self, sep: &str) -> String {
+        self.notes.join(sep)
+    }
 }
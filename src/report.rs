@@ -0,0 +1,198 @@
+use super::*;
+
+/// Display options shared by the text and markdown report renderers
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReportOptions {
+    /// Truncate each displayed note to this many characters (the stored notes are unchanged)
+    pub max_note_chars: Option<usize>,
+}
+
+impl ReportOptions {
+    /// Apply the display options to a single note
+    pub fn display_note(&self, note: &str) -> String {
+        match self.max_note_chars {
+            Some(max_chars) => truncate_chars(note, max_chars),
+            None => note.to_string(),
+        }
+    }
+}
+
+/// Generate the plain text report with default display options
+pub fn generate_sample_output(data: &TimeTrackingData) -> String {
+    generate_report(data, &ReportOptions::default())
+}
+
+/// Render the plain text report: start/end, totals, then each billing code with its notes
+pub fn generate_report(data: &TimeTrackingData, options: &ReportOptions) -> String {
+    let mut output = String::new();
+
+    if let (Some(start), Some(end)) = (&data.start_time, &data.end_time) {
+        output.push_str(&format!(
+            "Start Time: {} End Time: {}\n",
+            format_time(start),
+            format_time(end)
+        ));
+    }
+
+    output.push_str(&format!(
+        "Total Working Time: {} ({} hrs)\n",
+        Time::format_duration_minutes(data.total_minutes),
+        Time::format_duration_decimal(data.total_minutes)
+    ));
+
+    output.push_str(&format!(
+        "Total dead time: {} ({} hrs)\n",
+        Time::format_duration_minutes(data.dead_time_minutes),
+        Time::format_duration_decimal(data.dead_time_minutes)
+    ));
+
+    output.push('\n');
+
+    for project in &data.projects {
+        output.push_str(&format!(
+            "Billing Code: {} - {} ({} hrs)\n",
+            project.name,
+            Time::format_duration_minutes(project.total_minutes),
+            Time::format_duration_decimal(project.total_minutes)
+        ));
+
+        for note in &project.notes {
+            output.push_str(&format!("- {}\n", options.display_note(note)));
+        }
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Render a Markdown report: a summary block, a table of billing codes, then the
+/// notes for each project as bullets. Empty data renders just the header.
+pub fn generate_markdown_report(data: &TimeTrackingData, options: &ReportOptions) -> String {
+    let mut output = String::from("# Time Tracking Report\n");
+    if data.entries.is_empty() && data.projects.is_empty() {
+        return output;
+    }
+
+    output.push('\n');
+    output.push_str(&format!(
+        "- **Start Time:** {}\n",
+        data.formatted_start_time()
+    ));
+    output.push_str(&format!("- **End Time:** {}\n", data.formatted_end_time()));
+    output.push_str(&format!(
+        "- **Total Working Time:** {} ({} hrs)\n",
+        data.formatted_total_minutes(),
+        data.formatted_total_decimal()
+    ));
+    output.push_str(&format!(
+        "- **Total Dead Time:** {} ({} hrs)\n",
+        data.formatted_dead_time_minutes(),
+        data.formatted_dead_decimal()
+    ));
+
+    output.push_str("\n| Billing Code | Time | Hours |\n| --- | --- | --- |\n");
+    for project in &data.projects {
+        output.push_str(&format!(
+            "| {} | {} | {} |\n",
+            project.name.replace('|', "\\|"),
+            Time::format_duration_minutes(project.total_minutes),
+            Time::format_duration_decimal(project.total_minutes)
+        ));
+    }
+
+    for project in data.projects.iter().filter(|p| !p.notes.is_empty()) {
+        output.push_str(&format!("\n## {}\n\n", project.name));
+        for note in &project.notes {
+            output.push_str(&format!("- {}\n", options.display_note(note)));
+        }
+    }
+
+    output
+}
+
+/// Render an HTML fragment: a `<section>` with a summary paragraph, a table of billing
+/// codes and a list of notes per project. All text is HTML-escaped.
+pub fn generate_html_report(data: &TimeTrackingData, options: &ReportOptions) -> String {
+    let mut output = String::from("<section class=\"time-tracking-report\">\n");
+
+    output.push_str(&format!(
+        "<p>Start Time: {} End Time: {}<br>\nTotal Working Time: {} ({} hrs)<br>\nTotal Dead Time: {} ({} hrs)</p>\n",
+        data.formatted_start_time(),
+        data.formatted_end_time(),
+        data.formatted_total_minutes(),
+        data.formatted_total_decimal(),
+        data.formatted_dead_time_minutes(),
+        data.formatted_dead_decimal()
+    ));
+
+    output.push_str("<table>\n<thead><tr><th>Billing Code</th><th>Time</th><th>Hours</th></tr></thead>\n<tbody>\n");
+    for project in &data.projects {
+        output.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            html_escape(&project.name),
+            Time::format_duration_minutes(project.total_minutes),
+            Time::format_duration_decimal(project.total_minutes)
+        ));
+    }
+    output.push_str("</tbody>\n</table>\n");
+
+    for project in data.projects.iter().filter(|p| !p.notes.is_empty()) {
+        output.push_str(&format!("<h3>{}</h3>\n<ul>\n", html_escape(&project.name)));
+        for note in &project.notes {
+            output.push_str(&format!(
+                "<li>{}</li>\n",
+                html_escape(&options.display_note(note))
+            ));
+        }
+        output.push_str("</ul>\n");
+    }
+
+    output.push_str("</section>\n");
+    output
+}
+
+/// Escape the characters that are special in HTML text and attribute values
+fn html_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Render a diff-friendly summary: the total worked time, then each project sorted by
+/// name with its notes sorted lexically. Start/end and dead time are left out since they
+/// depend on entry order rather than the work that was logged.
+pub fn generate_stable_text(data: &TimeTrackingData) -> String {
+    let mut output = format!(
+        "Total: {} ({} hrs)\n",
+        data.formatted_total_minutes(),
+        data.formatted_total_decimal()
+    );
+
+    let mut projects: Vec<&ProjectSummary> = data.projects.iter().collect();
+    projects.sort_by(|a, b| a.name.cmp(&b.name));
+    for project in projects {
+        output.push_str(&format!(
+            "\n{}: {} ({} hrs)\n",
+            project.name,
+            Time::format_duration_minutes(project.total_minutes),
+            Time::format_duration_decimal(project.total_minutes)
+        ));
+
+        let mut notes: Vec<&String> = project.notes.iter().collect();
+        notes.sort();
+        for note in notes {
+            output.push_str(&format!("- {note}\n"));
+        }
+    }
+
+    output
+}
@@ -0,0 +1,28 @@
+use super::*;
+
+/// Direction to round durations in when snapping them to a billing increment
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoundingMode {
+    /// Round to the nearest multiple (halfway rounds up)
+    #[default]
+    Nearest,
+    /// Round up to the next multiple
+    Up,
+    /// Round down to the previous multiple
+    Down,
+}
+
+impl RoundingMode {
+    /// Round `minutes` to a multiple of `increment` in this direction.
+    /// An increment of 0 leaves the minutes unchanged.
+    pub fn round(&self, minutes: u32, increment: u32) -> u32 {
+        if increment == 0 {
+            return minutes;
+        }
+        match self {
+            RoundingMode::Nearest => Time::round_minutes(minutes, increment),
+            RoundingMode::Up => minutes.div_ceil(increment) * increment,
+            RoundingMode::Down => minutes / increment * increment,
+        }
+    }
+}
@@ -3,34 +3,93 @@ use std::{fmt::Display, str::FromStr};
 use super::*;
 
 mod hour;
+mod meridiem;
 mod minute;
+mod second;
 pub use hour::Hour;
+pub use meridiem::Meridiem;
 pub use minute::Minute;
+pub use second::Second;
 
-/// Represents a time in 12-hour format (no AM/PM needed as per requirements)
-#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+/// Represents a time in 12-hour format. AM/PM is optional; when it is missing the
+/// 12-hour period is inferred from the surrounding times.
+///
+/// Times with AM/PM are ordered by `to_minutes_24()`, so 11:00am sorts before 1:00pm.
+/// Times without it fall back to `to_minutes()`, i.e. clock order within the 12-hour
+/// representation: since `to_minutes` maps 12 to 0, 12:00 sorts before 1:00. Ties are
+/// broken by the hour as written (so an hour-0 time from `from_strings` sorts just before
+/// the matching 12), then the AM/PM marker (none, AM, PM), then seconds (none first), so
+/// two times compare equal exactly when they're `==`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Time {
     pub hour: Hour,
     pub minute: Minute,
+    #[serde(default)]
+    pub meridiem: Option<Meridiem>,
+    /// Seconds, for logs like "7:30:15-8:00:45". Minute-based math ignores them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub second: Option<Second>,
 }
 
 impl Time {
-    pub fn from_strings<T: AsRef<str>>(hour: T, minute: T) -> Result<Self, String> {
+    pub fn from_strings<T: AsRef<str>>(hour: T, minute: T) -> Result<Self, TimeParseError> {
         let hour: Hour = hour.as_ref().parse()?;
         let minute: Minute = minute.as_ref().parse()?;
-        Ok(Time { hour, minute })
+        Ok(Time {
+            hour,
+            minute,
+            meridiem: None,
+            second: None,
+        })
     }
 
-    pub fn new(hour: u8, minute: u8) -> Result<Self, String> {
+    pub fn new(hour: u8, minute: u8) -> Result<Self, TimeParseError> {
         if !(1..=12).contains(&hour) {
-            return Err(format!("Hour must be between 1 and 12, got {hour}"));
-        }
-        if minute > 59 {
-            return Err(format!("Minute must be between 0 and 59, got {minute}"));
+            return Err(TimeParseError::HourOutOfRange {
+                hour,
+                min: 1,
+                max: 12,
+            });
         }
         let hour: Hour = hour.try_into()?;
         let minute: Minute = minute.try_into()?;
-        Ok(Time { hour, minute })
+        Ok(Time {
+            hour,
+            minute,
+            meridiem: None,
+            second: None,
+        })
+    }
+
+    /// Attach seconds to this time
+    pub fn with_second(self, second: Second) -> Self {
+        Time {
+            second: Some(second),
+            ..self
+        }
+    }
+
+    /// Attach an AM/PM marker only if the time doesn't already have one
+    pub fn with_default_meridiem(self, meridiem: Meridiem) -> Self {
+        Time {
+            meridiem: self.meridiem.or(Some(meridiem)),
+            ..self
+        }
+    }
+
+    /// Create a time from a 24-hour clock hour (0-23), resolving AM/PM from the hour
+    pub fn new_24(hour: u8, minute: u8) -> Result<Self, TimeParseError> {
+        let (hour, meridiem) = Hour::from_24_hour(hour)?;
+        Ok(Time::new(hour.get(), minute)?.with_meridiem(meridiem))
+    }
+
+    /// Attach an explicit AM/PM marker to this time
+    pub fn with_meridiem(self, meridiem: Meridiem) -> Self {
+        Time {
+            meridiem: Some(meridiem),
+            ..self
+        }
     }
 
     /// Convert time to minutes since midnight (assuming 12-hour format)
@@ -39,9 +98,64 @@ impl Time {
         (hour_24 as u16 * 60) + self.minute.get() as u16
     }
 
-    /// Calculate duration in minutes between two times
-    /// This assumes both times are in the same 12-hour period
+    /// Inverse of `to_minutes`: build a time from minutes past 12:00 (0 maps back to 12)
+    pub fn from_minutes(total: u16) -> Result<Self, String> {
+        if total >= 12 * 60 {
+            return Err(format!(
+                "Minutes must be less than {} on a 12-hour clock, got {total}",
+                12 * 60
+            ));
+        }
+        let hour = match total / 60 {
+            0 => 12,
+            hour => hour,
+        };
+        Ok(Time::new(hour as u8, (total % 60) as u8)?)
+    }
+
+    /// Add (or with a negative value, subtract) minutes, wrapping around the 12-hour clock.
+    /// Times with an AM/PM marker wrap around the 24-hour clock and flip AM/PM as needed.
+    pub fn add_minutes(&self, minutes: i32) -> Self {
+        if let Some(total) = self.to_minutes_24() {
+            let total = (total as i32 + minutes).rem_euclid(24 * 60) as u16;
+            let (hour, meridiem) =
+                Hour::from_24_hour((total / 60) as u8).expect("hour is always below 24");
+            return Time::new(hour.get(), (total % 60) as u8)
+                .expect("minute is always below 60")
+                .with_meridiem(meridiem);
+        }
+
+        let total = (self.to_minutes() as i32 + minutes).rem_euclid(12 * 60) as u16;
+        Time::from_minutes(total).expect("wrapped minutes are always within 12 hours")
+    }
+
+    /// Convert time to minutes since midnight on a 24-hour clock.
+    /// Only available when the time carries an explicit AM/PM marker.
+    pub fn to_minutes_24(&self) -> Option<u16> {
+        let meridiem = self.meridiem?;
+        let offset = match meridiem {
+            Meridiem::Am => 0,
+            Meridiem::Pm => 12 * 60,
+        };
+        Some(self.to_minutes() + offset)
+    }
+
+    /// Duration between two times that both carry AM/PM, wrapping past midnight if needed
+    fn explicit_duration_minutes(&self, end: &Time) -> Option<u32> {
+        let start_mins = self.to_minutes_24()? as i32;
+        let end_mins = end.to_minutes_24()? as i32;
+        Some((end_mins - start_mins).rem_euclid(24 * 60) as u32)
+    }
+
+    /// Calculate duration in minutes between two times, used for an entry's length.
+    /// When both times carry AM/PM the explicit period is used, otherwise an end that
+    /// reads earlier on the clock than the start is taken to be in the next 12-hour
+    /// period, so "11-1" is 120 minutes.
     pub fn duration_minutes(&self, end: &Time) -> i32 {
+        if let Some(duration) = self.explicit_duration_minutes(end) {
+            return duration as i32;
+        }
+
         let start_mins = self.to_minutes() as i32;
         let end_mins = end.to_minutes() as i32;
 
@@ -53,9 +167,22 @@ impl Time {
         }
     }
 
-    /// Calculate duration in minutes between two times assuming chronological order
-    /// If end time appears "earlier" than start time, assume it's in the next 12-hour period
+    /// Calculate duration in seconds between two times, like `duration_minutes` but
+    /// counting seconds when either time has them
+    pub fn duration_seconds(&self, end: &Time) -> i32 {
+        let seconds = |time: &Time| time.second.map_or(0, |second| i32::from(second.get()));
+        self.duration_minutes(end) * 60 + seconds(end) - seconds(self)
+    }
+
+    /// Calculate duration in minutes between two times assuming chronological order,
+    /// used for the gap between one entry's end and the next one's start (see `gap`).
+    /// If end time appears "earlier" than start time, assume it's in the next 12-hour period.
+    /// This gives the same result as `duration_minutes`, as an unsigned value.
     pub fn chronological_duration_minutes(&self, end: &Time) -> u32 {
+        if let Some(duration) = self.explicit_duration_minutes(end) {
+            return duration;
+        }
+
         let start_mins = self.to_minutes() as u32;
         let end_mins = end.to_minutes() as u32;
 
@@ -79,8 +206,32 @@ impl Time {
 
     /// Format time as decimal hours
     pub fn format_duration_decimal(minutes: u32) -> String {
+        Self::format_duration_decimal_prec(minutes, 2)
+    }
+
+    /// Format time as decimal hours with `precision` digits after the point
+    pub fn format_duration_decimal_prec(minutes: u32, precision: usize) -> String {
         let hours = minutes as f32 / 60.0;
-        format!("{hours:.2}")
+        format!("{hours:.precision$}")
+    }
+
+    /// Round minutes to the nearest multiple of `increment` (halfway rounds up).
+    /// An increment of 0 leaves the minutes unchanged.
+    pub fn round_minutes(minutes: u32, increment: u32) -> u32 {
+        if increment == 0 {
+            return minutes;
+        }
+        (minutes + increment / 2) / increment * increment
+    }
+
+    /// Read the time as a duration since 12:00, e.g. "7.50 hours" for 7:30 or "1 hour" for 1:00
+    pub fn as_duration_string(&self) -> String {
+        let minutes = self.to_minutes() as u32;
+        if minutes == 60 {
+            "1 hour".to_string()
+        } else {
+            format!("{} hours", Self::format_duration_decimal(minutes))
+        }
     }
 
     pub fn gap(&self, other: &Time) -> u32 {
@@ -88,13 +239,44 @@ impl Time {
     }
 }
 
+impl PartialOrd for Time {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Time {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let key = |time: &Time| time.to_minutes_24().unwrap_or_else(|| time.to_minutes());
+        key(self)
+            .cmp(&key(other))
+            .then(self.hour.get().cmp(&other.hour.get()))
+            .then(self.meridiem.cmp(&other.meridiem))
+            .then(self.second.cmp(&other.second))
+    }
+}
+
+/// Parse a time the way entry lines are read, e.g. "7:30", "8" (minutes default to 00),
+/// "1:30pm" or "noon"
+impl FromStr for Time {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parser::parse_time(s.trim())
+    }
+}
+
+/// Renders the clock time, e.g. "7:30", "7:30:15" when seconds are known, or "7:30pm"
+/// when AM/PM is known
 impl Display for Time {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let minutes = self.to_minutes() as u32;
-        if minutes == 60 {
-            write!(f, "1 hour")
-        } else {
-            write!(f, "{} hours", Self::format_duration_decimal(minutes))
+        write!(f, "{}:{:02}", self.hour, self.minute.get())?;
+        if let Some(second) = self.second {
+            write!(f, ":{second}")?;
+        }
+        if let Some(meridiem) = self.meridiem {
+            write!(f, "{meridiem}")?;
         }
+        Ok(())
     }
 }
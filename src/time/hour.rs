@@ -26,10 +26,50 @@ use nutype::nutype;
 )]
 pub struct Hour(u8);
 
+/// Described as a plain integer, since that's how it's serialized
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Hour {
+    fn schema_name() -> String {
+        "Hour".to_string()
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        u8::json_schema(generator)
+    }
+}
+
 impl Hour {
     pub fn get(&self) -> u8 {
         *self.as_ref()
     }
+
+    /// Convert a 24-hour clock hour (0-23) into a 12-hour `Hour` plus its AM/PM marker
+    pub fn from_24_hour(value: u8) -> Result<(Self, Meridiem), TimeParseError> {
+        let (hour, meridiem) = match value {
+            0 => (12, Meridiem::Am),
+            1..=11 => (value, Meridiem::Am),
+            12 => (12, Meridiem::Pm),
+            13..=23 => (value - 12, Meridiem::Pm),
+            _ => {
+                return Err(TimeParseError::HourOutOfRange {
+                    hour: value,
+                    min: 0,
+                    max: 23,
+                });
+            }
+        };
+        Ok((hour.try_into()?, meridiem))
+    }
+
+    /// Convert this hour back to a 24-hour clock hour (0-23)
+    pub fn to_24_hour(&self, meridiem: Meridiem) -> u8 {
+        match (meridiem, self.get()) {
+            (Meridiem::Am, 12) => 0,
+            (Meridiem::Am, hour) => hour,
+            (Meridiem::Pm, 12) => 12,
+            (Meridiem::Pm, hour) => hour + 12,
+        }
+    }
 }
 
 impl PartialEq<u8> for Hour {
@@ -45,20 +85,25 @@ impl PartialOrd<u8> for Hour {
 }
 
 impl TryFrom<u8> for Hour {
-    type Error = String;
+    type Error = TimeParseError;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        Hour::try_new(value).map_err(|e| e.to_string())
+        Hour::try_new(value).map_err(|_| TimeParseError::HourOutOfRange {
+            hour: value,
+            min: 0,
+            max: 12,
+        })
     }
 }
 
 impl FromStr for Hour {
-    type Err = String;
+    type Err = TimeParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let hour: u8 = s
-            .parse()
-            .map_err(|_| format!("Invalid hour format: {}", s))?;
-        Hour::try_new(hour).map_err(|e| e.to_string())
+        let hour: u8 = s.parse().map_err(|_| TimeParseError::NotANumber {
+            unit: "hour",
+            text: s.to_string(),
+        })?;
+        hour.try_into()
     }
 }
@@ -0,0 +1,53 @@
+use super::*;
+
+/// Explicit AM/PM marker attached to a time (e.g. "7:30am" or "12:15p.m.")
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Meridiem {
+    Am,
+    Pm,
+}
+
+impl Meridiem {
+    /// The other half of the day
+    pub fn opposite(self) -> Meridiem {
+        match self {
+            Meridiem::Am => Meridiem::Pm,
+            Meridiem::Pm => Meridiem::Am,
+        }
+    }
+
+    /// Split a trailing meridiem suffix off a time string, returning the remaining
+    /// time and the parsed marker. Accepts "am"/"pm"/"a"/"p" in any case, with or without periods.
+    pub fn split_suffix(time_str: &str) -> (&str, Option<Meridiem>) {
+        let time_end = time_str
+            .trim_end_matches(|c: char| c.is_ascii_alphabetic() || c == '.')
+            .len();
+        let (time, suffix) = time_str.split_at(time_end);
+        match suffix.parse() {
+            Ok(meridiem) => (time.trim_end(), Some(meridiem)),
+            Err(_) => (time_str, None),
+        }
+    }
+}
+
+impl FromStr for Meridiem {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.replace('.', "").to_ascii_lowercase().as_str() {
+            "am" | "a" => Ok(Meridiem::Am),
+            "pm" | "p" => Ok(Meridiem::Pm),
+            _ => Err(format!("Invalid AM/PM marker: {s}")),
+        }
+    }
+}
+
+impl Display for Meridiem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Meridiem::Am => write!(f, "am"),
+            Meridiem::Pm => write!(f, "pm"),
+        }
+    }
+}
@@ -25,6 +25,18 @@ use nutype::nutype;
 )]
 pub struct Minute(u8);
 
+/// Described as a plain integer, since that's how it's serialized
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Minute {
+    fn schema_name() -> String {
+        "Minute".to_string()
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        u8::json_schema(generator)
+    }
+}
+
 impl Minute {
     pub fn get(&self) -> u8 {
         *self.as_ref()
@@ -50,20 +62,21 @@ impl PartialOrd<u8> for Minute {
 }
 
 impl TryFrom<u8> for Minute {
-    type Error = String;
+    type Error = TimeParseError;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        Minute::try_new(value).map_err(|e| e.to_string())
+        Minute::try_new(value).map_err(|_| TimeParseError::MinuteOutOfRange { minute: value })
     }
 }
 
 impl FromStr for Minute {
-    type Err = String;
+    type Err = TimeParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let hour: u8 = s
-            .parse()
-            .map_err(|_| format!("Invalid hour format: {}", s))?;
-        Minute::try_new(hour).map_err(|e| e.to_string())
+        let minute: u8 = s.parse().map_err(|_| TimeParseError::NotANumber {
+            unit: "minute",
+            text: s.to_string(),
+        })?;
+        minute.try_into()
     }
 }
@@ -0,0 +1,82 @@
+use super::*;
+
+use nutype::nutype;
+
+#[nutype(
+    derive(
+        Debug,
+        Copy,
+        Clone,
+        Deserialize,
+        Serialize,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        Hash,
+        AsRef,
+        Borrow
+    ),
+    validate(predicate =
+        |value: &u8| {
+            (0..=59).contains(value)
+        }
+    )
+)]
+pub struct Second(u8);
+
+/// Described as a plain integer, since that's how it's serialized
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Second {
+    fn schema_name() -> String {
+        "Second".to_string()
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        u8::json_schema(generator)
+    }
+}
+
+impl Second {
+    pub fn get(&self) -> u8 {
+        *self.as_ref()
+    }
+}
+
+impl Display for Second {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}", self.as_ref())
+    }
+}
+
+impl PartialEq<u8> for Second {
+    fn eq(&self, other: &u8) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl PartialOrd<u8> for Second {
+    fn partial_cmp(&self, other: &u8) -> Option<std::cmp::Ordering> {
+        self.as_ref().partial_cmp(other)
+    }
+}
+
+impl TryFrom<u8> for Second {
+    type Error = TimeParseError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Second::try_new(value).map_err(|_| TimeParseError::SecondOutOfRange { second: value })
+    }
+}
+
+impl FromStr for Second {
+    type Err = TimeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let second: u8 = s.parse().map_err(|_| TimeParseError::NotANumber {
+            unit: "second",
+            text: s.to_string(),
+        })?;
+        second.try_into()
+    }
+}
@@ -1,16 +1,124 @@
 use super::*;
 
 /// Represents a time period with associated project and notes
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TimeEntry {
     pub start: Time,
     pub end: Time,
     pub project: String,
     pub notes: Vec<String>,
+    #[serde(default)]
+    pub date: Option<Date>,
+    /// Leading employee/context tag (e.g. "SC" in "SC 8-9 project")
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// 1-based line number of the entry in the original input
+    #[serde(default)]
+    pub line_number: usize,
+    /// Logged duration in minutes for duration-only entries like "1h30m review", which
+    /// have no clock times. `None` for entries with a time range.
+    #[serde(default)]
+    pub duration: Option<u32>,
+    /// Tags split off the project, e.g. ["backend"] for "api#backend"
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Nesting depth of each note, from indentation before its bullet (two spaces or a
+    /// tab per level). Empty when every note is top-level; see `note_depth`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub note_depths: Vec<usize>,
+    /// The entry runs past midnight, e.g. "10pm-6am" (or "10-6" with
+    /// `ParserConfig::overnight`). Only known when both times carry AM/PM. `date` is
+    /// still the day the entry started.
+    #[serde(default)]
+    pub crosses_midnight: bool,
+}
+
+/// A note with the sub-notes nested under it, as returned by `TimeEntry::notes_tree`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct NoteNode {
+    pub text: String,
+    pub children: Vec<NoteNode>,
 }
 
 impl TimeEntry {
+    /// Create a duration-only entry with no project or notes. Its start and end are
+    /// placeholders (both 12:00) and shouldn't be read as clock times.
+    pub fn from_duration(minutes: u32) -> Self {
+        let placeholder = Time::new(12, 0).expect("12:00 is a valid time");
+        TimeEntry {
+            start: placeholder,
+            end: placeholder,
+            project: String::new(),
+            notes: Vec::new(),
+            date: None,
+            tag: None,
+            line_number: 0,
+            duration: Some(minutes),
+            tags: Vec::new(),
+            note_depths: Vec::new(),
+            crosses_midnight: false,
+        }
+    }
+
+    /// Whether the entry was logged with clock times rather than just a duration
+    pub fn is_clocked(&self) -> bool {
+        self.duration.is_none()
+    }
+
+    /// Nesting depth of the note at `index` (0 for top-level notes)
+    pub fn note_depth(&self, index: usize) -> usize {
+        self.note_depths.get(index).copied().unwrap_or(0)
+    }
+
+    /// Add a note nested `depth` levels deep
+    pub fn add_note(&mut self, note: String, depth: usize) {
+        if depth > 0 && self.note_depths.is_empty() {
+            self.note_depths = vec![0; self.notes.len()];
+        }
+        if !self.note_depths.is_empty() {
+            self.note_depths.push(depth);
+        }
+        self.notes.push(note);
+    }
+
+    /// The notes as a tree, nesting each note under the closest preceding note one level
+    /// shallower. A note indented more than one level past the previous note is nested
+    /// just one level deeper.
+    pub fn notes_tree(&self) -> Vec<NoteNode> {
+        let mut tree: Vec<NoteNode> = Vec::new();
+        let mut max_depth = 0;
+        for (index, note) in self.notes.iter().enumerate() {
+            let depth = self.note_depth(index).min(max_depth);
+            let mut siblings = &mut tree;
+            for _ in 0..depth {
+                siblings = &mut siblings
+                    .last_mut()
+                    .expect("a parent exists at every shallower depth")
+                    .children;
+            }
+            siblings.push(NoteNode {
+                text: note.clone(),
+                children: Vec::new(),
+            });
+            max_depth = depth + 1;
+        }
+        tree
+    }
+
     pub fn duration_minutes(&self) -> u32 {
-        self.start.duration_minutes(&self.end) as u32
+        match self.duration {
+            Some(minutes) => minutes,
+            None => self.start.duration_minutes(&self.end) as u32,
+        }
+    }
+
+    /// Duration in seconds, counting the seconds of clock times that have them
+    pub fn duration_seconds(&self) -> u32 {
+        match self.duration {
+            Some(minutes) => minutes * 60,
+            None => self.start.duration_seconds(&self.end).max(0) as u32,
+        }
     }
 }
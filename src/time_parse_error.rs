@@ -0,0 +1,40 @@
+use std::fmt::Display;
+
+/// Error returned when building a `Time` or one of its parts from numbers or strings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeParseError {
+    /// The hour is outside `min..=max` (1-12 for a 12-hour time, 0-23 for a 24-hour one)
+    HourOutOfRange { hour: u8, min: u8, max: u8 },
+    /// The minute is over 59
+    MinuteOutOfRange { minute: u8 },
+    /// The second is over 59
+    SecondOutOfRange { second: u8 },
+    /// The text for a part of the time ("hour", "minute" or "second") isn't a number
+    NotANumber { unit: &'static str, text: String },
+}
+
+impl Display for TimeParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeParseError::HourOutOfRange { hour, min, max } => {
+                write!(f, "Hour must be between {min} and {max}, got {hour}")
+            }
+            TimeParseError::MinuteOutOfRange { minute } => {
+                write!(f, "Minute must be between 0 and 59, got {minute}")
+            }
+            TimeParseError::SecondOutOfRange { second } => {
+                write!(f, "Second must be between 0 and 59, got {second}")
+            }
+            TimeParseError::NotANumber { unit, text } => write!(f, "Invalid {unit} format: {text}"),
+        }
+    }
+}
+
+impl std::error::Error for TimeParseError {}
+
+/// Lets the parser, which reports problems as plain messages, use `?` on time conversions
+impl From<TimeParseError> for String {
+    fn from(error: TimeParseError) -> Self {
+        error.to_string()
+    }
+}
@@ -1,14 +1,28 @@
+use std::collections::HashMap;
+
 use super::*;
 
 /// Main struct holding all parsed time tracking data
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct TimeTrackingData {
     pub total_minutes: u32,
     pub dead_time_minutes: u32,
     pub projects: Vec<ProjectSummary>,
-    pub warnings: Vec<String>,
+    /// Problems found while reading the input (see `validation_warnings` for the rest)
+    pub warnings: Vec<ParseWarning>,
+    /// Problems found by `validate_entries` once the entries were parsed, such as long
+    /// entries, large gaps or out-of-order times
+    #[serde(default)]
+    pub validation_warnings: Vec<ParseWarning>,
     pub start_time: Option<Time>,
     pub end_time: Option<Time>,
+    /// Date taken from the first entry carrying a date prefix (e.g. "6/3 8-9 project")
+    #[serde(default)]
+    pub date: Option<Date>,
+    /// Every parsed entry in source order, including ones missing a project name
+    #[serde(default)]
+    pub entries: Vec<TimeEntry>,
 }
 
 impl TimeTrackingData {
@@ -16,6 +30,55 @@ impl TimeTrackingData {
         Self::default()
     }
 
+    /// Fold another summary (e.g. another day) into this one: totals and dead time are
+    /// summed, warnings and entries appended, projects unioned by name (summing minutes
+    /// and sessions and appending notes), and start/end widened to the earliest/latest
+    pub fn merge(&mut self, other: &TimeTrackingData) {
+        self.total_minutes += other.total_minutes;
+        self.dead_time_minutes += other.dead_time_minutes;
+        self.warnings.extend(other.warnings.iter().cloned());
+        self.validation_warnings
+            .extend(other.validation_warnings.iter().cloned());
+        self.entries.extend(other.entries.iter().cloned());
+
+        for project in &other.projects {
+            match self.projects.iter_mut().find(|p| p.name == project.name) {
+                Some(existing) => {
+                    existing.add_time(project.total_minutes);
+                    existing.add_notes(project.notes.clone());
+                    existing.add_tags(&project.tags);
+                    existing.session_count += project.session_count;
+                }
+                None => self.projects.push(project.clone()),
+            }
+        }
+        self.projects.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // Compare the spans on the clock from the start of the day, so an end that reads
+        // earlier (5:00 after 11:30) but falls later in the day still wins
+        if let (Some(ours), Some(theirs)) = (self.clock_span(), other.clock_span()) {
+            self.start_time = Some(if theirs.0 < ours.0 { theirs.1 } else { ours.1 });
+            self.end_time = Some(if theirs.2 > ours.2 { theirs.3 } else { ours.3 });
+        } else {
+            self.start_time = self.start_time.or(other.start_time);
+            self.end_time = self.end_time.or(other.end_time);
+        }
+        self.date = self.date.or(other.date);
+    }
+
+    /// The day's start and end as (minutes into the day, start, minutes into the day, end),
+    /// placing the start with `minutes_into_day` and the end after it
+    fn clock_span(&self) -> Option<(u32, Time, u32, Time)> {
+        let (start, end) = (self.start_time?, self.end_time?);
+        let from = minutes_into_day(&start);
+        Some((
+            from,
+            start,
+            from + start.chronological_duration_minutes(&end),
+            end,
+        ))
+    }
+
     /// Serialize the data to JSON string
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(self)
@@ -26,9 +89,274 @@ impl TimeTrackingData {
         serde_json::to_string_pretty(self)
     }
 
-    /// Deserialize from JSON string
+    /// Serialize the data to a JSON string with camelCase keys (`totalMinutes`,
+    /// `deadTimeMinutes`, ...) throughout, for JavaScript consumers
+    pub fn to_json_camel(&self) -> Result<String, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        camel_case_keys(&mut value);
+        serde_json::to_string(&value)
+    }
+
+    /// Serialize the data to a JSON string where each project's notes are a
+    /// single string joined by `sep` rather than an array
+    pub fn to_json_joined_notes(&self, sep: &str) -> Result<String, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        if let Some(projects) = value["projects"].as_array_mut() {
+            for (project, summary) in projects.iter_mut().zip(&self.projects) {
+                project["notes"] = summary.notes_joined(sep).into();
+            }
+        }
+        serde_json::to_string(&value)
+    }
+
+    /// Serialize the data to a JSON string with durations in seconds: `total_seconds`,
+    /// `dead_time_seconds` and each project's `total_seconds` replace the minute fields
+    pub fn to_json_seconds(&self) -> Result<String, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        minutes_to_seconds(&mut value, "total");
+        minutes_to_seconds(&mut value, "dead_time");
+        if let Some(projects) = value["projects"].as_array_mut() {
+            projects
+                .iter_mut()
+                .for_each(|project| minutes_to_seconds(project, "total"));
+        }
+        serde_json::to_string(&value)
+    }
+
+    /// Deserialize from JSON string. Accepts durations either in minutes or in the
+    /// seconds form written by `to_json_seconds`.
     pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
-        serde_json::from_str(json)
+        let mut value: serde_json::Value = serde_json::from_str(json)?;
+        if !value.is_object() {
+            return Err(serde::de::Error::custom(
+                "expected a JSON object for time tracking data",
+            ));
+        }
+        seconds_to_minutes(&mut value, "total");
+        seconds_to_minutes(&mut value, "dead_time");
+        if let Some(projects) = value
+            .get_mut("projects")
+            .and_then(serde_json::Value::as_array_mut)
+        {
+            projects
+                .iter_mut()
+                .for_each(|project| seconds_to_minutes(project, "total"));
+        }
+        serde_json::from_value(value)
+    }
+
+    /// Serialize the data to a YAML string
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(self)
+    }
+
+    /// Deserialize from a YAML string
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(yaml: &str) -> Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+
+    /// Serialize a summary of the data to a TOML string. Since TOML has no null, the
+    /// start/end times and date are written as strings ("7:30", "6/3/2024") and left out
+    /// when unknown, and warnings are written as their messages. Entries aren't included.
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        #[derive(Serialize)]
+        struct TomlSummary<'a> {
+            total_minutes: u32,
+            dead_time_minutes: u32,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            start_time: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            end_time: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            date: Option<String>,
+            warnings: Vec<String>,
+            projects: &'a [ProjectSummary],
+        }
+
+        toml::to_string(&TomlSummary {
+            total_minutes: self.total_minutes,
+            dead_time_minutes: self.dead_time_minutes,
+            start_time: self.start_time.map(|time| time.to_string()),
+            end_time: self.end_time.map(|time| time.to_string()),
+            date: self.date.map(|date| date.to_string()),
+            warnings: self
+                .all_warnings()
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            projects: &self.projects,
+        })
+    }
+
+    /// Export project summaries as CSV with a `project,minutes,hours,notes` header.
+    /// Notes are joined with "; " and fields are quoted when needed.
+    pub fn to_csv(&self) -> String {
+        let mut output = String::from("project,minutes,hours,notes\n");
+        for project in &self.projects {
+            output.push_str(&format!(
+                "{},{},{},{}\n",
+                csv_field(&project.name),
+                project.total_minutes,
+                Time::format_duration_decimal(project.total_minutes),
+                csv_field(&project.notes_joined("; "))
+            ));
+        }
+        output
+    }
+
+    /// Write the entries back out in the canonical input format (`H:MM-H:MM project`
+    /// followed by `- note` lines), so the output parses to equivalent data. Leading
+    /// tags are written before the range, to be read back with `ParserConfig::leading_tag`.
+    pub fn to_tracking_text(&self) -> String {
+        let mut output = String::new();
+        for entry in &self.entries {
+            if let Some(tag) = &entry.tag {
+                output.push_str(&format!("{tag} "));
+            }
+            if let Some(date) = &entry.date {
+                output.push_str(&format!("{date} "));
+            }
+            let when = match entry.duration {
+                Some(minutes) => format_duration_hm(minutes),
+                None => format!("{}-{}", entry.start, entry.end),
+            };
+            output.push_str(&format!("{when} {}", entry.project));
+            for tag in &entry.tags {
+                output.push_str(&format!("#{tag}"));
+            }
+            output.push('\n');
+            for (index, note) in entry.notes.iter().enumerate() {
+                let indent = "  ".repeat(entry.note_depth(index));
+                output.push_str(&format!("{indent}- {note}\n"));
+            }
+        }
+        output
+    }
+
+    /// Export the clocked entries as an iCalendar (.ics) document with one VEVENT per
+    /// entry on `date`. Times are resolved to a 24-hour clock along the reconstructed
+    /// timeline (see `timeline`), so afternoon entries get afternoon hours and entries
+    /// past midnight land on the next day. A first start without AM/PM is taken as
+    /// morning (or noon for 12), and a date without a year uses the year of `created_at`.
+    /// Duration-only entries have no clock times and are left out.
+    ///
+    /// `created_at` is when the document is generated, as a Unix timestamp in seconds. It
+    /// becomes each event's UTC `DTSTAMP`, so the output only depends on the arguments.
+    /// Content lines longer than 75 octets are folded as RFC 5545 requires.
+    pub fn to_ics(&self, date: Date, created_at: u64) -> String {
+        let stamp_date = Date::from_unix_seconds(created_at);
+        let date = Date {
+            year: date.year.or(stamp_date.year),
+            ..date
+        };
+        let seconds_of_day = created_at % 86_400;
+        let dtstamp = format!(
+            "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+            stamp_date.year.unwrap_or_default(),
+            stamp_date.month,
+            stamp_date.day,
+            seconds_of_day / 3600,
+            seconds_of_day / 60 % 60,
+            seconds_of_day % 60
+        );
+        let timeline = self.timeline();
+        let first_start = self
+            .entries
+            .iter()
+            .find(|entry| entry.is_clocked())
+            .map_or(0, |entry| minutes_into_day(&entry.start));
+        let ics_time = |offset: u32| {
+            let minutes = first_start + offset;
+            let day = (0..minutes / (24 * 60)).fold(date, |day, _| day.next_day());
+            let minutes = minutes % (24 * 60);
+            format!(
+                "{:04}{:02}{:02}T{:02}{:02}00",
+                day.year.unwrap_or_default(),
+                day.month,
+                day.day,
+                minutes / 60,
+                minutes % 60
+            )
+        };
+
+        let mut output = String::new();
+        ics_line(&mut output, "BEGIN:VCALENDAR");
+        ics_line(&mut output, "VERSION:2.0");
+        ics_line(&mut output, "PRODID:-//time-tracking-parser//EN");
+        for (index, (entry, &(start, end))) in self.entries.iter().zip(&timeline).enumerate() {
+            if !entry.is_clocked() {
+                continue;
+            }
+            let dtstart = ics_time(start);
+            ics_line(&mut output, "BEGIN:VEVENT");
+            ics_line(
+                &mut output,
+                &format!("UID:{dtstart}-{index}@time-tracking-parser"),
+            );
+            ics_line(&mut output, &format!("DTSTAMP:{dtstamp}"));
+            ics_line(&mut output, &format!("DTSTART:{dtstart}"));
+            ics_line(&mut output, &format!("DTEND:{}", ics_time(end)));
+            ics_line(
+                &mut output,
+                &format!("SUMMARY:{}", ics_text(&entry.project)),
+            );
+            if !entry.notes.is_empty() {
+                let notes = ics_text(&entry.notes.join("\n"));
+                ics_line(&mut output, &format!("DESCRIPTION:{notes}"));
+            }
+            ics_line(&mut output, "END:VEVENT");
+        }
+        ics_line(&mut output, "END:VCALENDAR");
+        output
+    }
+
+    /// Render a Markdown report with a summary, a billing code table and notes per project
+    pub fn to_markdown(&self) -> String {
+        self.to_markdown_with(&ReportOptions::default())
+    }
+
+    /// Render a Markdown report using the given display options
+    pub fn to_markdown_with(&self, options: &ReportOptions) -> String {
+        generate_markdown_report(self, options)
+    }
+
+    /// Render an HTML fragment with a summary, a billing code table and notes per project
+    pub fn to_html(&self) -> String {
+        generate_html_report(self, &ReportOptions::default())
+    }
+
+    /// Render a normalized summary with projects and notes in a canonical order, so
+    /// committed daily logs diff cleanly (see `generate_stable_text`)
+    pub fn to_stable_text(&self) -> String {
+        generate_stable_text(self)
+    }
+
+    /// Stable content hash for change detection. Covers the totals, start/end times and
+    /// the name-sorted projects, so equal data hashes the same however it was built.
+    /// Every field is fed in as fixed-width little-endian bytes, so the hash is the same
+    /// on 32- and 64-bit targets (e.g. wasm32).
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = FnvHasher::default();
+        hasher.write_u32(self.total_minutes);
+        hasher.write_u32(self.dead_time_minutes);
+        hasher.write_time(self.start_time);
+        hasher.write_time(self.end_time);
+
+        let mut projects: Vec<&ProjectSummary> = self.projects.iter().collect();
+        projects.sort_by(|a, b| a.name.cmp(&b.name));
+        for project in projects {
+            hasher.write_str(&project.name);
+            hasher.write_u32(project.total_minutes);
+            hasher.write_len(project.notes.len());
+            for note in &project.notes {
+                hasher.write_str(note);
+            }
+        }
+
+        hasher.0
     }
 
     pub fn formatted_start_time(&self) -> String {
@@ -56,39 +384,773 @@ impl TimeTrackingData {
         Time::format_duration_decimal(self.dead_time_minutes)
     }
 
-    pub fn validate_entries(&mut self, entries: &[TimeEntry]) {
-        // Check for potential time order issues (duration > 6 hours or large gaps)
-        self.validate_durations(entries);
+    /// The day on one line, e.g. "7:30–2:30 · 7.00h worked · 0.00h idle · 3 projects".
+    /// The time range is left off when the start or end is unknown.
+    pub fn summary_line(&self) -> String {
+        let mut parts = Vec::with_capacity(4);
+        if let (Some(start), Some(end)) = (&self.start_time, &self.end_time) {
+            parts.push(format!("{}–{}", format_time(start), format_time(end)));
+        }
+        parts.push(format!("{}h worked", self.formatted_total_decimal()));
+        parts.push(format!("{}h idle", self.formatted_dead_decimal()));
+        parts.push(match self.projects.len() {
+            1 => "1 project".to_string(),
+            count => format!("{count} projects"),
+        });
+        parts.join(" · ")
+    }
+
+    /// The project with the most minutes, ties going to the alphabetically first name
+    pub fn busiest_project(&self) -> Option<&ProjectSummary> {
+        self.projects.iter().min_by(|a, b| {
+            b.total_minutes
+                .cmp(&a.total_minutes)
+                .then_with(|| a.name.cmp(&b.name))
+        })
+    }
+
+    /// Share of the day spent idle: dead time over worked plus dead time (0.0 when empty)
+    pub fn dead_time_ratio(&self) -> f32 {
+        let span = self.total_minutes + self.dead_time_minutes;
+        if span == 0 {
+            return 0.0;
+        }
+        self.dead_time_minutes as f32 / span as f32
+    }
+
+    /// Wall-clock minutes from the first start to the last end, covering both working
+    /// and dead time. `None` when there are no clocked entries.
+    pub fn span_minutes(&self) -> Option<u32> {
+        let (start, end) = (self.start_time?, self.end_time?);
+        Some(start.chronological_duration_minutes(&end))
+    }
+
+    /// The longest stretch of back-to-back work with no gap, merging consecutive entries
+    /// that touch whatever their project, as (start, end, minutes). The first such
+    /// stretch wins a tie. `None` when there are no clocked entries.
+    pub fn longest_focus_block(&self) -> Option<(Time, Time, u32)> {
+        let mut longest: Option<(Time, Time, u32)> = None;
+        let mut current: Option<(Time, Time, u32, u32)> = None;
+
+        for (entry, &(start, end)) in self.entries.iter().zip(&self.timeline()) {
+            if !entry.is_clocked() {
+                continue;
+            }
+            current = match current {
+                Some((block_start, _, block_from, block_to)) if block_to == start => {
+                    Some((block_start, entry.end, block_from, end))
+                }
+                _ => Some((entry.start, entry.end, start, end)),
+            };
+            if let Some((block_start, block_end, from, to)) = current
+                && longest.is_none_or(|(_, _, minutes)| to - from > minutes)
+            {
+                longest = Some((block_start, block_end, to - from));
+            }
+        }
+        longest
+    }
+
+    /// Minutes worked over (positive) or under (negative) an expected day length
+    pub fn variance_from(&self, expected_minutes: u32) -> i32 {
+        self.total_minutes as i32 - expected_minutes as i32
+    }
+
+    /// Whether more than `expected_minutes` were worked
+    pub fn is_overtime(&self, expected_minutes: u32) -> bool {
+        self.variance_from(expected_minutes) > 0
+    }
+
+    /// Every note paired with its project's name, project by project in note order
+    pub fn all_notes(&self) -> Vec<(String, String)> {
+        self.projects
+            .iter()
+            .flat_map(|project| {
+                project
+                    .notes
+                    .iter()
+                    .map(|note| (project.name.clone(), note.clone()))
+            })
+            .collect()
+    }
+
+    /// Look up a project summary by its exact name
+    pub fn project(&self, name: &str) -> Option<&ProjectSummary> {
+        self.projects.iter().find(|project| project.name == name)
+    }
+
+    /// Look up a project summary by name, ignoring case (e.g. "Admin" finds "admin")
+    pub fn project_ci(&self, name: &str) -> Option<&ProjectSummary> {
+        let name = name.to_lowercase();
+        self.projects
+            .iter()
+            .find(|project| project.name.to_lowercase() == name)
+    }
+
+    /// Parse warnings followed by validation warnings
+    pub fn all_warnings(&self) -> Vec<ParseWarning> {
+        self.warnings
+            .iter()
+            .chain(&self.validation_warnings)
+            .cloned()
+            .collect()
+    }
+
+    /// How far the parsed data can be trusted, from 0.0 to 1.0. Starts at 1.0 and
+    /// subtracts a penalty per warning (parse or validation), clamped at 0:
+    /// - 0.25 for an unparseable time range (time was dropped)
+    /// - 0.15 for a suspiciously long entry, large gap, out-of-order entry or total over
+    ///   24 hours
+    /// - 0.1 for an entry missing its project name, with zero duration, duplicating the
+    ///   previous entry or with an ambiguous noon/midnight crossing (strict mode only)
+    /// - 0.05 for a skipped over-long line, open-ended entry or unrecognized line
+    pub fn parse_confidence(&self) -> f64 {
+        let penalty: f64 = self
+            .all_warnings()
+            .iter()
+            .map(|warning| match warning {
+                ParseWarning::InvalidTimeRange { .. } => 0.25,
+                ParseWarning::LongDuration { .. }
+                | ParseWarning::LargeGap { .. }
+                | ParseWarning::EntriesOutOfOrder { .. }
+                | ParseWarning::TotalExceedsDay { .. } => 0.15,
+                ParseWarning::MissingProjectName { .. }
+                | ParseWarning::ZeroDuration { .. }
+                | ParseWarning::DuplicateEntry { .. }
+                | ParseWarning::AmbiguousTime { .. } => 0.1,
+                ParseWarning::LineTooLong { .. }
+                | ParseWarning::OpenEndedEntry { .. }
+                | ParseWarning::UnrecognizedLine { .. } => 0.05,
+            })
+            .sum();
+        (1.0 - penalty).max(0.0)
+    }
+
+    /// Total minutes across all projects except the named ones (e.g. admin or meetings)
+    pub fn total_excluding(&self, names: &[&str]) -> u32 {
+        self.projects
+            .iter()
+            .filter(|project| !names.contains(&project.name.as_str()))
+            .map(|project| project.total_minutes)
+            .sum()
+    }
+
+    /// Signed difference between billing each project rounded up to a multiple of
+    /// `increment` minutes and the actual `total_minutes`, i.e. how much rounding
+    /// over-bills. An increment of 0 means no rounding and a delta of 0.
+    pub fn rounding_delta(&self, increment: u32) -> i64 {
+        let billed: i64 = self
+            .projects
+            .iter()
+            .map(|project| i64::from(RoundingMode::Up.round(project.total_minutes, increment)))
+            .sum();
+        billed - i64::from(self.total_minutes)
+    }
+
+    /// Round each project's total to a multiple of `increment` minutes in the given
+    /// direction and recompute `total_minutes` from the rounded projects.
+    /// Dead time and the individual entries are left as logged.
+    pub fn round_to_increment(&mut self, increment: u32, mode: RoundingMode) {
+        for project in &mut self.projects {
+            project.total_minutes = mode.round(project.total_minutes, increment);
+        }
+        self.total_minutes = self
+            .projects
+            .iter()
+            .map(|project| project.total_minutes)
+            .sum();
+    }
+
+    /// Total logged time in seconds, counting the seconds of clock times like
+    /// "7:30:15". Unlike `total_minutes` this is never rounded.
+    pub fn total_seconds(&self) -> u32 {
+        self.entries.iter().map(TimeEntry::duration_seconds).sum()
+    }
+
+    /// Fraction of the logged minutes belonging to entries with at least one note,
+    /// or 0.0 when nothing was logged
+    pub fn documented_ratio(&self) -> f64 {
+        if self.total_minutes == 0 {
+            return 0.0;
+        }
+        let documented: u32 = self
+            .entries
+            .iter()
+            .filter(|entry| !entry.notes.is_empty())
+            .map(TimeEntry::duration_minutes)
+            .sum();
+        f64::from(documented) / f64::from(self.total_minutes)
+    }
+
+    /// Each project's unrounded percentage of `total_minutes`, in project order
+    /// (see `ProjectSummary::percent_of`)
+    pub fn project_percentages(&self) -> Vec<(String, f32)> {
+        self.projects
+            .iter()
+            .map(|project| (project.name.clone(), project.percent_of(self.total_minutes)))
+            .collect()
+    }
+
+    /// Total billed amount across all projects at the given hourly `rates` by project
+    /// name, using `default_rate` for projects without a rate
+    pub fn cost(&self, rates: &HashMap<String, f64>, default_rate: f64) -> f64 {
+        self.costs(rates, default_rate)
+            .iter()
+            .map(|(_, cost)| cost)
+            .sum()
+    }
+
+    /// Billed amount per project, in project order (see `cost`)
+    pub fn costs(&self, rates: &HashMap<String, f64>, default_rate: f64) -> Vec<(String, f64)> {
+        self.projects
+            .iter()
+            .map(|project| {
+                let rate = rates.get(&project.name).copied().unwrap_or(default_rate);
+                let cost = f64::from(project.total_minutes) / 60.0 * rate;
+                (project.name.clone(), cost)
+            })
+            .collect()
+    }
+
+    /// Total minutes per leading entry tag (see `ParserConfig::leading_tag`).
+    /// Untagged entries aren't counted.
+    pub fn minutes_by_tag(&self) -> HashMap<String, u32> {
+        let mut totals = HashMap::new();
+        for entry in &self.entries {
+            if let Some(tag) = &entry.tag {
+                *totals.entry(tag.clone()).or_insert(0) += entry.duration_minutes();
+            }
+        }
+        totals
+    }
+
+    /// Total minutes per project tag (like "backend" in "api#backend"), summing every
+    /// project carrying the tag, so a project with two tags counts toward both.
+    /// Untagged projects are grouped under "(untagged)"; see `by_tag_with`.
+    pub fn by_tag(&self) -> HashMap<String, u32> {
+        self.by_tag_with(Some("(untagged)"))
+    }
+
+    /// Like `by_tag`, grouping untagged projects under `untagged_key`, or leaving them
+    /// out when it's `None`
+    pub fn by_tag_with(&self, untagged_key: Option<&str>) -> HashMap<String, u32> {
+        let mut totals = HashMap::new();
+        for project in &self.projects {
+            if project.tags.is_empty() {
+                if let Some(key) = untagged_key {
+                    *totals.entry(key.to_string()).or_insert(0) += project.total_minutes;
+                }
+                continue;
+            }
+            for tag in &project.tags {
+                *totals.entry(tag.clone()).or_insert(0) += project.total_minutes;
+            }
+        }
+        totals
+    }
+
+    /// Reconstruct the day as a single timeline, returning each entry's (start, end)
+    /// offset in minutes from the first entry's start. Entries are assumed to be in
+    /// chronological order, using the same gap logic as the dead time calculation.
+    /// Duration-only entries aren't on the clock, so they get an empty slot where they appear.
+    pub fn timeline(&self) -> Vec<(u32, u32)> {
+        let mut timeline: Vec<(u32, u32)> = Vec::with_capacity(self.entries.len());
+        let mut previous: Option<&TimeEntry> = None;
+        let mut offset = 0;
+
+        for entry in &self.entries {
+            if !entry.is_clocked() {
+                timeline.push((offset, offset));
+                continue;
+            }
+
+            let start = match previous {
+                Some(previous) => offset + previous.end.gap(&entry.start),
+                None => 0,
+            };
+            offset = start + entry.duration_minutes();
+            timeline.push((start, offset));
+            previous = Some(entry);
+        }
+
+        timeline
+    }
+
+    /// Pair each entry with the idle minutes between it and the next clocked entry
+    /// (0 for the last one). Duration-only entries aren't on the clock and get 0.
+    pub fn entries_with_following_gap(&self) -> Vec<(&TimeEntry, u32)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let next = self.entries[index + 1..]
+                    .iter()
+                    .find(|next| next.is_clocked());
+                let gap = match next {
+                    Some(next) if entry.is_clocked() => entry.end.gap(&next.start),
+                    _ => 0,
+                };
+                (entry, gap)
+            })
+            .collect()
+    }
+
+    /// Collapse runs of back-to-back entries for the same project (e.g. "8-9 proj" then
+    /// "9-10 proj") into single blocks, concatenating their notes in order and combining
+    /// their tags.
+    /// Entries separated by a gap, another project or a duration-only entry stay separate.
+    pub fn merged_entries(&self) -> Vec<TimeEntry> {
+        let mut merged: Vec<TimeEntry> = Vec::with_capacity(self.entries.len());
+        for entry in &self.entries {
+            if let Some(previous) = merged.last_mut()
+                && previous.is_clocked()
+                && entry.is_clocked()
+                && previous.project == entry.project
+                && previous.end == entry.start
+            {
+                previous.end = entry.end;
+                previous.crosses_midnight |= entry.crosses_midnight;
+                for (index, note) in entry.notes.iter().enumerate() {
+                    previous.add_note(note.clone(), entry.note_depth(index));
+                }
+                for tag in &entry.tags {
+                    if !previous.tags.contains(tag) {
+                        previous.tags.push(tag.clone());
+                    }
+                }
+            } else {
+                merged.push(entry.clone());
+            }
+        }
+        merged
+    }
+
+    /// Check whether the logged time accounts for the whole day: no gap between consecutive
+    /// entries may exceed `max_gap` minutes, and the total idle time (the reconstructed span
+    /// minus worked minutes) may not exceed `max_gap` either. Empty data is never complete.
+    pub fn is_complete(&self, max_gap: u32) -> bool {
+        let timeline = self.timeline();
+        let Some(&(_, span)) = timeline.last() else {
+            return false;
+        };
+
+        let gaps_ok = timeline
+            .windows(2)
+            .all(|pair| pair[1].0 - pair[0].1 <= max_gap);
+        let worked: u32 = timeline.iter().map(|&(start, end)| end - start).sum();
+
+        gaps_ok && span - worked <= max_gap
+    }
+
+    /// Render the reconstructed day as a one-line sparkline where each character
+    /// covers `slice_minutes`: a space when idle, a full block when working the whole
+    /// slice, and a partial block otherwise
+    pub fn sparkline(&self, slice_minutes: u32) -> String {
+        const BLOCKS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+        let timeline = self.timeline();
+        let span = timeline.last().map_or(0, |&(_, end)| end);
+        if slice_minutes == 0 || span == 0 {
+            return String::new();
+        }
+
+        (0..span.div_ceil(slice_minutes))
+            .map(|slice| {
+                let slice_start = slice * slice_minutes;
+                let slice_end = (slice_start + slice_minutes).min(span);
+                let worked: u32 = timeline
+                    .iter()
+                    .map(|&(start, end)| end.min(slice_end).saturating_sub(start.max(slice_start)))
+                    .sum();
+                let length = slice_end - slice_start;
+
+                if worked == 0 {
+                    BLOCKS[0]
+                } else if worked >= length {
+                    BLOCKS[8]
+                } else {
+                    BLOCKS[(worked * 7).div_ceil(length) as usize]
+                }
+            })
+            .collect()
+    }
+
+    /// Unique project names (in first-appearance order) of the entries overlapping the
+    /// `start`-`end` window. The window is placed on the reconstructed timeline (see
+    /// `timeline`) nearest to the logged day, so a morning window matches morning entries.
+    pub fn projects_in_window(&self, start: Time, end: Time) -> Vec<&str> {
+        let Some(first) = self.entries.iter().find(|entry| entry.is_clocked()) else {
+            return Vec::new();
+        };
+        let timeline = self.timeline();
+        let span = timeline.last().map_or(0, |&(_, end)| end) as i64;
+
+        // The window start is either after the day's start or just before it
+        let after = first.start.gap(&start) as i64;
+        let window_start = if after - span <= 12 * 60 - after {
+            after
+        } else {
+            after - 12 * 60
+        };
+        let window = (
+            window_start,
+            window_start + start.chronological_duration_minutes(&end) as i64,
+        );
+
+        let mut projects: Vec<&str> = Vec::new();
+        for (entry, &(entry_start, entry_end)) in self.entries.iter().zip(&timeline) {
+            if overlaps((entry_start as i64, entry_end as i64), window)
+                && !projects.contains(&entry.project.as_str())
+            {
+                projects.push(&entry.project);
+            }
+        }
+        projects
+    }
+
+    pub fn validate_entries(&mut self, entries: &[TimeEntry], config: &ValidationConfig) {
+        // Check for potential time order issues (overly long entries or large gaps)
+        self.validate_durations(entries, config.max_entry_minutes);
 
         // Check for large gaps between consecutive entries that might indicate wrong order
-        self.validate_dead_time(entries);
+        self.validate_dead_time(entries, config);
+
+        // Check for entries that start and end at the same time (likely a typo)
+        self.validate_zero_durations(entries);
+
+        // Check for entries starting before the one logged above them
+        self.validate_order(entries);
+
+        // Check for an entry repeating the one above it (likely pasted twice)
+        self.validate_duplicates(entries);
+
+        // In strict mode, flag every guessed noon/midnight crossing
+        if config.strict_meridiem {
+            self.validate_meridiem(entries);
+        }
     }
 
-    fn validate_durations(&mut self, entries: &[TimeEntry]) {
-        for entry in entries {
+    fn validate_durations(&mut self, entries: &[TimeEntry], max_minutes: u32) {
+        for entry in entries.iter().filter(|entry| entry.is_clocked()) {
             let duration = entry.duration_minutes();
-            if duration > 8 * 60 {
-                self.warnings.push(format!(
-                "Time period {}-{} appears to be longer than 8 hours. Input may not be in correct order.",
-                format_time(&entry.start),
-                format_time(&entry.end)
-            ));
+            if duration > max_minutes {
+                self.validation_warnings.push(ParseWarning::LongDuration {
+                    line_number: entry.line_number,
+                    start: entry.start,
+                    end: entry.end,
+                    minutes: duration,
+                    limit_minutes: max_minutes,
+                });
+            }
+        }
+    }
+
+    /// Flag entries that start earlier in the day than the previous entry. Without AM/PM
+    /// the 12-hour clock may wrap past noon once per day, either within an entry ("11-1")
+    /// or into a start between 12 and 6 ("10-11" then "1-2"). Times with AM/PM compare on
+    /// the 24-hour clock, which may likewise wrap past midnight once ("10pm-midnight" then
+    /// "midnight-1"). Any other step back on the clock is out of order.
+    fn validate_order(&mut self, entries: &[TimeEntry]) {
+        // Whether the 12-hour clock has already wrapped past noon
+        let mut wrapped = false;
+        // Minutes added to 24-hour times once the day has run past midnight
+        let mut past_midnight = 0;
+        let mut previous: Option<(&TimeEntry, u32, Option<u32>)> = None;
+
+        for entry in entries.iter().filter(|entry| entry.is_clocked()) {
+            let mut position =
+                u32::from(entry.start.to_minutes()) + if wrapped { 12 * 60 } else { 0 };
+            let mut position_24 = entry
+                .start
+                .to_minutes_24()
+                .map(|minutes| u32::from(minutes) + past_midnight);
+
+            if let Some((previous, previous_position, previous_24)) = previous {
+                let hour = entry.start.hour.into_inner();
+                let out_of_order = match (previous_24, position_24) {
+                    (Some(previous_start), Some(start)) if start >= previous_start => false,
+                    (Some(_), Some(start)) if past_midnight == 0 && start < 6 * 60 => {
+                        past_midnight = 24 * 60;
+                        position_24 = Some(start + past_midnight);
+                        false
+                    }
+                    (Some(_), Some(_)) => true,
+                    _ if position >= previous_position => false,
+                    _ if !wrapped && (hour == 12 || hour <= 6) => {
+                        wrapped = true;
+                        position += 12 * 60;
+                        false
+                    }
+                    _ => true,
+                };
+
+                if out_of_order {
+                    self.validation_warnings
+                        .push(ParseWarning::EntriesOutOfOrder {
+                            line_number: entry.line_number,
+                            previous_start: previous.start,
+                            start: entry.start,
+                        });
+                }
+            }
+
+            // An entry ending earlier on the clock than it started runs past noon (or,
+            // with AM/PM, past midnight)
+            if entry.end.to_minutes() < entry.start.to_minutes() {
+                wrapped = true;
+            }
+            if let (Some(start), Some(end)) =
+                (entry.start.to_minutes_24(), entry.end.to_minutes_24())
+                && end < start
+            {
+                past_midnight = 24 * 60;
+            }
+            previous = Some((entry, position, position_24));
+        }
+    }
+
+    fn validate_meridiem(&mut self, entries: &[TimeEntry]) {
+        let clocked: Vec<&TimeEntry> = entries.iter().filter(|entry| entry.is_clocked()).collect();
+        let mut ambiguous = |line_number, from: Time, to: Time| {
+            if is_ambiguous_crossing(&from, &to) {
+                self.validation_warnings.push(ParseWarning::AmbiguousTime {
+                    line_number,
+                    from,
+                    to,
+                });
+            }
+        };
+
+        for (index, entry) in clocked.iter().enumerate() {
+            if index > 0 {
+                ambiguous(entry.line_number, clocked[index - 1].end, entry.start);
+            }
+            ambiguous(entry.line_number, entry.start, entry.end);
+        }
+    }
+
+    fn validate_zero_durations(&mut self, entries: &[TimeEntry]) {
+        for entry in entries.iter().filter(|entry| entry.is_clocked()) {
+            if entry.duration_minutes() == 0 {
+                self.validation_warnings.push(ParseWarning::ZeroDuration {
+                    line_number: entry.line_number,
+                    start: entry.start,
+                    end: entry.end,
+                });
             }
         }
     }
 
-    fn validate_dead_time(&mut self, entries: &[TimeEntry]) {
-        entries.windows(2).for_each(|chunk| {
+    fn validate_duplicates(&mut self, entries: &[TimeEntry]) {
+        for pair in entries.windows(2) {
+            if let [previous, entry] = pair
+                && previous.is_clocked()
+                && entry.is_clocked()
+                && previous.start == entry.start
+                && previous.end == entry.end
+                && previous.project == entry.project
+            {
+                self.validation_warnings.push(ParseWarning::DuplicateEntry {
+                    line_number: entry.line_number,
+                    start: entry.start,
+                    end: entry.end,
+                    project: entry.project.clone(),
+                });
+            }
+        }
+    }
+
+    fn validate_dead_time(&mut self, entries: &[TimeEntry], config: &ValidationConfig) {
+        let max_minutes = config.max_gap_minutes;
+        let clocked: Vec<&TimeEntry> = entries.iter().filter(|entry| entry.is_clocked()).collect();
+        clocked.windows(2).for_each(|chunk| {
             if let [first, second] = chunk {
                 let gap = first.end.gap(&second.start);
-                if gap > 6 * 60 {
-                    self.warnings.push(format!(
-                    "Gap from {} to {} appears to be longer than 6 hours. Input may not be in correct order.",
-                    format_time(&first.end),
-                    format_time(&second.start)
-                ));
+                let unexpected = gap - config.break_minutes(&first.end, &second.start);
+                if unexpected > max_minutes {
+                    self.validation_warnings.push(ParseWarning::LargeGap {
+                        line_number: second.line_number,
+                        from: first.end,
+                        to: second.start,
+                        minutes: gap,
+                        limit_minutes: max_minutes,
+                    });
                 }
             }
         });
     }
 }
+
+/// Whether two half-open (start, end) minute ranges share any time
+fn overlaps(a: (i64, i64), b: (i64, i64)) -> bool {
+    a.0 < b.1 && b.0 < a.1
+}
+
+/// Replace the `{name}_minutes` field of a JSON object with `{name}_seconds`
+fn minutes_to_seconds(value: &mut serde_json::Value, name: &str) {
+    if let Some(object) = value.as_object_mut()
+        && let Some(minutes) = object.remove(&format!("{name}_minutes"))
+    {
+        let seconds = minutes.as_u64().map(|minutes| minutes * 60);
+        object.insert(format!("{name}_seconds"), seconds.into());
+    }
+}
+
+/// Replace a `{name}_seconds` field of a JSON object with `{name}_minutes`, if present
+fn seconds_to_minutes(value: &mut serde_json::Value, name: &str) {
+    if let Some(object) = value.as_object_mut()
+        && let Some(seconds) = object.remove(&format!("{name}_seconds"))
+    {
+        let minutes = seconds.as_u64().map(|seconds| seconds / 60);
+        object.insert(format!("{name}_minutes"), minutes.into());
+    }
+}
+
+/// Rename every `snake_case` object key in a JSON value to `camelCase`, recursively
+fn camel_case_keys(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(object) => {
+            *object = std::mem::take(object)
+                .into_iter()
+                .map(|(key, mut value)| {
+                    camel_case_keys(&mut value);
+                    (camel_case(&key), value)
+                })
+                .collect();
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(camel_case_keys),
+        _ => {}
+    }
+}
+
+/// Convert a `snake_case` name to `camelCase`
+fn camel_case(name: &str) -> String {
+    let mut words = name.split('_');
+    let first = words.next().unwrap_or_default().to_string();
+    words.fold(first, |mut camel, word| {
+        let mut chars = word.chars();
+        if let Some(initial) = chars.next() {
+            camel.extend(initial.to_uppercase());
+            camel.push_str(chars.as_str());
+        }
+        camel
+    })
+}
+
+/// Minutes from midnight to a time, reading a time without AM/PM as morning except for
+/// 12:xx, which is taken as just after noon
+pub(crate) fn minutes_into_day(time: &Time) -> u32 {
+    match time.to_minutes_24() {
+        Some(minutes) => u32::from(minutes),
+        None if time.hour == 12 => u32::from(time.to_minutes()) + 720,
+        None => u32::from(time.to_minutes()),
+    }
+}
+
+/// Whether going from one time to the next without AM/PM means guessing that the clock
+/// wrapped past noon or midnight. Ending exactly at 12:00 (e.g. "11-12") isn't a guess.
+pub(crate) fn is_ambiguous_crossing(from: &Time, to: &Time) -> bool {
+    if from.meridiem.is_some() || to.meridiem.is_some() {
+        return false;
+    }
+    let to_minutes = match to.to_minutes() {
+        0 if to.minute.get() == 0 => 12 * 60,
+        minutes => minutes,
+    };
+    to_minutes < from.to_minutes()
+}
+
+/// Append an iCalendar content line with its CRLF, folding it into chunks of at most
+/// 75 octets (continuation lines start with a space) without splitting a character
+fn ics_line(output: &mut String, line: &str) {
+    let mut rest = line;
+    let mut limit = 75;
+    loop {
+        let mut split = rest.len().min(limit);
+        while !rest.is_char_boundary(split) {
+            split -= 1;
+        }
+        output.push_str(&rest[..split]);
+        output.push_str("\r\n");
+        rest = &rest[split..];
+        if rest.is_empty() {
+            break;
+        }
+        output.push(' ');
+        limit = 74;
+    }
+}
+
+/// Escape text for an iCalendar property value
+fn ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// JSON Schema describing the output of `TimeTrackingData::to_json` (and
+/// `parse_time_data_to_json`), with `ProjectSummary`, `Time` and the other nested types
+/// under its definitions
+#[cfg(feature = "schema")]
+pub fn json_schema() -> serde_json::Value {
+    serde_json::to_value(schemars::schema_for!(TimeTrackingData))
+        .expect("a JSON Schema always serializes")
+}
+
+/// Quote a CSV field if it contains a comma, quote or newline, doubling any quotes
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// FNV-1a hasher, used instead of `DefaultHasher` so hashes are stable across builds.
+/// Values are written through the methods below rather than `Hash`, whose length
+/// prefixes and enum discriminants are platform-sized.
+struct FnvHasher(u64);
+
+impl FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_len(&mut self, len: usize) {
+        self.write(&(len as u64).to_le_bytes());
+    }
+
+    fn write_str(&mut self, text: &str) {
+        self.write_len(text.len());
+        self.write(text.as_bytes());
+    }
+
+    /// A missing time, then hour, minute, AM/PM and seconds as one byte each
+    fn write_time(&mut self, time: Option<Time>) {
+        let Some(time) = time else {
+            self.write(&[0]);
+            return;
+        };
+        let meridiem = match time.meridiem {
+            None => 0,
+            Some(Meridiem::Am) => 1,
+            Some(Meridiem::Pm) => 2,
+        };
+        let second = time.second.map_or(u8::MAX, |second| second.get());
+        self.write(&[1, time.hour.get(), time.minute.get(), meridiem, second]);
+    }
+}
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+/// Thresholds for the sanity checks run over parsed entries
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidationConfig {
+    /// Entries longer than this many minutes produce a `LongDuration` warning
+    pub max_entry_minutes: u32,
+    /// Gaps between entries longer than this many minutes produce a `LargeGap` warning
+    pub max_gap_minutes: u32,
+    /// Warn about every entry or gap that only makes sense by assuming it crosses noon or
+    /// midnight (e.g. "11-1"), rather than silently guessing
+    pub strict_meridiem: bool,
+    /// An expected break, like lunch from 12 to 1. Time between entries that falls in it
+    /// isn't dead time and doesn't count toward the `LargeGap` threshold.
+    pub lunch: Option<(Time, Time)>,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        Self {
+            max_entry_minutes: 8 * 60,
+            max_gap_minutes: 6 * 60,
+            strict_meridiem: false,
+            lunch: None,
+        }
+    }
+}
+
+impl ValidationConfig {
+    /// Minutes of the gap from `from` to `to` that fall within the lunch break. Times are
+    /// compared on the 12-hour clock, or the 24-hour clock when all of them have AM/PM.
+    pub fn break_minutes(&self, from: &Time, to: &Time) -> u32 {
+        let Some((break_start, break_end)) = self.lunch else {
+            return 0;
+        };
+        let gap = i64::from(from.gap(to));
+        let length = i64::from(break_start.chronological_duration_minutes(&break_end));
+        let (period, gap_start, break_start) = match (
+            from.to_minutes_24(),
+            to.to_minutes_24(),
+            break_start.to_minutes_24(),
+            break_end.to_minutes_24(),
+        ) {
+            (Some(from), Some(_), Some(break_start), Some(_)) => (24 * 60, from, break_start),
+            _ => (12 * 60, from.to_minutes(), break_start.to_minutes()),
+        };
+        let gap_start = i64::from(gap_start);
+
+        // The break recurs every period, so check the occurrences the gap could touch
+        let overlap: i64 = (-1..=2)
+            .map(|period_index| {
+                let start = i64::from(break_start) + period_index * period;
+                ((gap_start + gap).min(start + length) - gap_start.max(start)).max(0)
+            })
+            .sum();
+        overlap.min(gap) as u32
+    }
+}
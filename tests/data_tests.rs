@@ -0,0 +1,505 @@
+use std::collections::HashMap;
+
+use time_tracking_parser::*;
+
+const BASIC_INPUT: &str = r#"7:30-8 someproject
+8-8:30 admin
+- discussing staffing with colleague
+8:30-11 someproject
+- investigated issue, pushed PR
+* pushed potential fix for component /build url handling
+11-12 other-project
+tech connect
+12-12:30 admin
+- 1:1 w/ coworker
+12:30-2:30 someproject
+- discussing work items and how to complete"#;
+
+#[test]
+fn test_total_excluding() {
+    let data = parse_time_tracking_data(BASIC_INPUT, None, None);
+
+    assert_eq!(data.total_excluding(&["admin"]), 360);
+    assert_eq!(data.total_excluding(&["admin", "other-project"]), 300);
+    assert_eq!(data.total_excluding(&[]), data.total_minutes);
+
+    // Read-only view: the data itself is untouched
+    assert_eq!(data.total_minutes, 420);
+    assert_eq!(data.projects.len(), 3);
+}
+
+#[test]
+fn test_sparkline() {
+    let input = r#"7-8 project1
+9-10 project2
+10-10:15 project3"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    // Span is 7:00 to 10:15 = 195 minutes, so 7 half-hour slices
+    let sparkline = data.sparkline(30);
+    assert_eq!(sparkline.chars().count(), 7);
+    assert_eq!(sparkline, "██  ███");
+
+    let sparkline = data.sparkline(60);
+    assert_eq!(sparkline.chars().count(), 4);
+    assert!(sparkline.starts_with("█ █"));
+}
+
+#[test]
+fn test_sparkline_partial_slice() {
+    let input = r#"7-7:15 project1
+8-9 project2"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    let sparkline: Vec<char> = data.sparkline(60).chars().collect();
+    assert_eq!(sparkline.len(), 2);
+    assert_ne!(sparkline[0], ' ');
+    assert_ne!(sparkline[0], '█');
+    assert_eq!(sparkline[1], '█');
+}
+
+#[test]
+fn test_sparkline_empty() {
+    let data = parse_time_tracking_data("", None, None);
+    assert_eq!(data.sparkline(30), "");
+}
+
+#[test]
+fn test_is_complete() {
+    let data = parse_time_tracking_data(BASIC_INPUT, None, None);
+    assert!(data.is_complete(30));
+
+    let input = r#"8-9 project1
+9:15-12 project2
+12:30-1 project1"#;
+    let data = parse_time_tracking_data(input, None, None);
+    assert!(data.is_complete(45));
+    assert!(!data.is_complete(20));
+
+    // A 3-hour unexplained gap
+    let input = r#"8-9 project1
+12-1 project2"#;
+    let data = parse_time_tracking_data(input, None, None);
+    assert!(!data.is_complete(60));
+
+    assert!(!TimeTrackingData::new().is_complete(60));
+}
+
+#[test]
+fn test_content_hash() {
+    let data = parse_time_tracking_data(BASIC_INPUT, None, None);
+    let restored = TimeTrackingData::from_json(&data.to_json().unwrap()).unwrap();
+    assert_eq!(data.content_hash(), restored.content_hash());
+
+    // Project order doesn't matter
+    let mut reordered = data.clone();
+    reordered.projects.reverse();
+    assert_eq!(data.content_hash(), reordered.content_hash());
+
+    // Content does
+    let mut changed = data.clone();
+    changed.projects[0].notes.push("another note".to_string());
+    assert_ne!(data.content_hash(), changed.content_hash());
+
+    let other = parse_time_tracking_data("7-8 project1", None, None);
+    assert_ne!(data.content_hash(), other.content_hash());
+
+    // Pinned so it can't drift between targets or releases
+    assert_eq!(other.content_hash(), 0x217d_1731_adab_74a0);
+}
+
+#[test]
+fn test_projects_in_window() {
+    let data = parse_time_tracking_data(BASIC_INPUT, None, None);
+
+    let morning = data.projects_in_window(Time::new(7, 0).unwrap(), Time::new(11, 0).unwrap());
+    assert_eq!(morning, vec!["someproject", "admin"]);
+
+    let lunch = data.projects_in_window(Time::new(11, 30).unwrap(), Time::new(1, 0).unwrap());
+    assert_eq!(lunch, vec!["other-project", "admin", "someproject"]);
+
+    let evening = data.projects_in_window(Time::new(5, 0).unwrap(), Time::new(6, 0).unwrap());
+    assert!(evening.is_empty());
+}
+
+#[test]
+fn test_rounding_delta() {
+    let input = r#"8-8:07 project1
+8:07-8:14 project2
+8:14-8:21 project3"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    // Each 7-minute project bills as 15 minutes: 45 - 21
+    assert_eq!(data.rounding_delta(15), 24);
+    assert_eq!(data.rounding_delta(1), 0);
+    assert_eq!(data.rounding_delta(0), 0);
+}
+
+#[test]
+fn test_documented_ratio() {
+    let data = parse_time_tracking_data("8-9 project1\n- wrote docs\n9-10 project2", None, None);
+    assert_eq!(data.documented_ratio(), 0.5);
+
+    assert_eq!(TimeTrackingData::new().documented_ratio(), 0.0);
+}
+
+#[test]
+fn test_entries_with_following_gap() {
+    let data =
+        parse_time_tracking_data("8-9 project1\n9-10 project2\n10:45-11 project1", None, None);
+
+    let gaps: Vec<u32> = data
+        .entries_with_following_gap()
+        .into_iter()
+        .map(|(_, gap)| gap)
+        .collect();
+    assert_eq!(gaps, vec![0, 45, 0]);
+    assert_eq!(data.entries_with_following_gap()[1].0.project, "project2");
+}
+
+#[test]
+fn test_merged_entries() {
+    let input = r#"8-9 proj
+- first
+9-9:30 proj
+9:30-10 proj
+- second
+10-11 other
+11:30-12 proj"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+    let merged = data.merged_entries();
+
+    assert_eq!(merged.len(), 3);
+    assert_eq!(merged[0].start, Time::new(8, 0).unwrap());
+    assert_eq!(merged[0].end, Time::new(10, 0).unwrap());
+    assert_eq!(merged[0].notes, vec!["first", "second"]);
+    assert_eq!(merged[0].duration_minutes(), 120);
+
+    // Not adjacent to the earlier run, so it stays its own block
+    assert_eq!(merged[2].project, "proj");
+    assert_eq!(merged[2].duration_minutes(), 30);
+
+    let total: u32 = merged.iter().map(TimeEntry::duration_minutes).sum();
+    assert_eq!(total, data.total_minutes);
+}
+
+#[test]
+fn test_project_percentages() {
+    let data = parse_time_tracking_data("8-9 projectA\n9-9:40 projectB", None, None);
+
+    assert_eq!(
+        data.project_percentages(),
+        vec![
+            ("projectA".to_string(), 60.0),
+            ("projectB".to_string(), 40.0)
+        ]
+    );
+    assert_eq!(data.projects[0].percent_of(0), 0.0);
+    assert!(TimeTrackingData::new().project_percentages().is_empty());
+}
+
+#[test]
+fn test_project_lookup() {
+    let data = parse_time_tracking_data(BASIC_INPUT, None, None);
+
+    assert_eq!(data.project("admin").unwrap().total_minutes, 60);
+    assert!(data.project("missing-project").is_none());
+    assert!(data.project("Admin").is_none());
+
+    assert_eq!(data.project_ci("Admin").unwrap().name, "admin");
+    assert!(data.project_ci("ADMINISTRATION").is_none());
+}
+
+#[test]
+fn test_parse_confidence() {
+    let clean = parse_time_tracking_data(BASIC_INPUT, None, None);
+    assert_eq!(clean.parse_confidence(), 1.0);
+
+    let messy = parse_time_tracking_data(
+        "8-9 project1\n25:70-8 project2\n9-6 project3\n2-3",
+        None,
+        None,
+    );
+    assert!(messy.parse_confidence() < 0.5);
+
+    let hopeless = parse_time_tracking_data(
+        "1-2 a\n25:70-8 b\n26-8 c\n27-8 d\n28-8 e\n29-8 f",
+        None,
+        None,
+    );
+    assert_eq!(hopeless.parse_confidence(), 0.0);
+}
+
+#[test]
+fn test_round_to_increment() {
+    let input = "8-8:37 project1\n9-9:38 project2";
+
+    let mut up = parse_time_tracking_data(input, None, None);
+    up.round_to_increment(15, RoundingMode::Up);
+    assert_eq!(up.project("project1").unwrap().total_minutes, 45);
+    assert_eq!(up.project("project2").unwrap().total_minutes, 45);
+    assert_eq!(up.total_minutes, 90);
+    assert_eq!(up.dead_time_minutes, 23);
+
+    let mut nearest = parse_time_tracking_data(input, None, None);
+    nearest.round_to_increment(15, RoundingMode::Nearest);
+    assert_eq!(nearest.project("project1").unwrap().total_minutes, 30);
+    assert_eq!(nearest.project("project2").unwrap().total_minutes, 45);
+    assert_eq!(nearest.total_minutes, 75);
+
+    let mut down = parse_time_tracking_data(input, None, None);
+    down.round_to_increment(15, RoundingMode::Down);
+    assert_eq!(down.total_minutes, 60);
+}
+
+#[test]
+fn test_cost() {
+    let data = parse_time_tracking_data(BASIC_INPUT, None, None);
+    let rates = HashMap::from([
+        ("someproject".to_string(), 120.0),
+        ("admin".to_string(), 60.0),
+    ]);
+
+    // someproject 5h at 120, admin 1h at 60, other-project 1h at the default 90
+    assert_eq!(
+        data.costs(&rates, 90.0),
+        vec![
+            ("admin".to_string(), 60.0),
+            ("other-project".to_string(), 90.0),
+            ("someproject".to_string(), 600.0),
+        ]
+    );
+    assert_eq!(data.cost(&rates, 90.0), 750.0);
+}
+
+#[test]
+fn test_merge() {
+    let mut week = parse_time_tracking_data("8-9 projectA\n- monday work\n9-10 admin", None, None);
+    let tuesday = parse_time_tracking_data(
+        "7:30-9 projectA\n- tuesday work\n10-11:30 projectB",
+        None,
+        None,
+    );
+
+    week.merge(&tuesday);
+
+    assert_eq!(week.total_minutes, 120 + 180);
+    assert_eq!(week.dead_time_minutes, 60);
+    assert_eq!(week.projects.len(), 3);
+    let project_a = week.project("projectA").unwrap();
+    assert_eq!(project_a.total_minutes, 150);
+    assert_eq!(project_a.notes, vec!["monday work", "tuesday work"]);
+    assert_eq!(project_a.session_count, 2);
+    assert_eq!(week.start_time, Some(Time::new(7, 30).unwrap()));
+    assert_eq!(week.end_time, Some(Time::new(11, 30).unwrap()));
+    assert_eq!(week.entries.len(), 4);
+}
+
+#[test]
+fn test_merge_span_crossing_noon() {
+    let mut data = parse_time_tracking_data("8-5 a", None, None);
+    data.merge(&parse_time_tracking_data("9-11:30 b", None, None));
+    assert_eq!(data.start_time, Some(Time::new(8, 0).unwrap()));
+    assert_eq!(data.end_time, Some(Time::new(5, 0).unwrap()));
+
+    let mut data = parse_time_tracking_data("9-11:30 b", None, None);
+    data.merge(&parse_time_tracking_data("12:30-2 c\n7-8 a", None, None));
+    assert_eq!(data.start_time, Some(Time::new(9, 0).unwrap()));
+    assert_eq!(data.end_time, Some(Time::new(8, 0).unwrap()));
+}
+
+#[test]
+fn test_busiest_project_and_dead_time_ratio() {
+    let data = parse_time_tracking_data(BASIC_INPUT, None, None);
+    assert_eq!(data.busiest_project().unwrap().name, "someproject");
+    assert_eq!(data.dead_time_ratio(), 0.0);
+
+    // Ties go to the alphabetically first name
+    let tied =
+        parse_time_tracking_data("8-9 zeta\n9-10 alpha\n11-11:30 beta\n1-2 gamma", None, None);
+    assert_eq!(tied.busiest_project().unwrap().name, "alpha");
+    // 60 idle minutes from 10 to 11 and 90 from 11:30 to 1, against 210 worked
+    assert_eq!(tied.dead_time_ratio(), 150.0 / 360.0);
+
+    let empty = TimeTrackingData::new();
+    assert!(empty.busiest_project().is_none());
+    assert_eq!(empty.dead_time_ratio(), 0.0);
+}
+
+#[test]
+fn test_total_exceeds_day_warning() {
+    let data = parse_time_tracking_data("8-4 alpha\n4-12 beta\n12-8 gamma\n8-9 delta", None, None);
+
+    assert_eq!(data.total_minutes, 25 * 60);
+    assert!(
+        data.validation_warnings
+            .contains(&ParseWarning::TotalExceedsDay {
+                line_number: 4,
+                total_minutes: 25 * 60,
+            })
+    );
+
+    let day = parse_time_tracking_data("8-4 alpha\n4-12 beta\n12-8 gamma", None, None);
+    assert!(
+        !day.validation_warnings
+            .iter()
+            .any(|warning| matches!(warning, ParseWarning::TotalExceedsDay { .. }))
+    );
+}
+
+#[test]
+fn test_by_tag() {
+    let input = "8-9 api#backend\n9-10:30 worker#backend#infra\n10:30-11 standup";
+    let data = parse_time_tracking_data(input, None, None);
+
+    let totals = data.by_tag();
+    assert_eq!(totals.len(), 3);
+    assert_eq!(totals["backend"], 150);
+    assert_eq!(totals["infra"], 90);
+    assert_eq!(totals["(untagged)"], 30);
+
+    let totals = data.by_tag_with(None);
+    assert_eq!(
+        totals,
+        HashMap::from([("backend".to_string(), 150), ("infra".to_string(), 90)])
+    );
+}
+
+#[test]
+fn test_project_summary_note_accessors() {
+    let mut summary = ProjectSummary::new("projectA".to_string());
+    assert_eq!(summary.note_count(), 0);
+    assert_eq!(summary.first_note(), None);
+    assert_eq!(summary.last_note(), None);
+
+    summary.add_notes(vec![
+        "triaged".to_string(),
+        "fixed".to_string(),
+        "deployed".to_string(),
+    ]);
+    assert_eq!(summary.note_count(), 3);
+    assert_eq!(summary.first_note(), Some("triaged"));
+    assert_eq!(summary.last_note(), Some("deployed"));
+}
+
+#[test]
+fn test_duplicate_entry_warning() {
+    let data = parse_time_tracking_data("8-9 proj\n8-9 proj\n9-10 proj", None, None);
+
+    let duplicates: Vec<&ParseWarning> = data
+        .validation_warnings
+        .iter()
+        .filter(|warning| matches!(warning, ParseWarning::DuplicateEntry { .. }))
+        .collect();
+    assert_eq!(
+        duplicates,
+        vec![&ParseWarning::DuplicateEntry {
+            line_number: 2,
+            start: Time::new(8, 0).unwrap(),
+            end: Time::new(9, 0).unwrap(),
+            project: "proj".to_string(),
+        }]
+    );
+
+    // Back-to-back entries on the same project are fine
+    let data = parse_time_tracking_data("8-9 proj\n9-10 proj", None, None);
+    assert!(data.validation_warnings.is_empty());
+}
+
+#[test]
+fn test_variance_from_expected_day() {
+    let data = parse_time_tracking_data("8-12 alpha\n1-3 beta", None, None);
+    assert_eq!(data.total_minutes, 360);
+    assert_eq!(data.variance_from(480), -120);
+    assert!(!data.is_overtime(480));
+
+    assert_eq!(data.variance_from(300), 60);
+    assert!(data.is_overtime(300));
+    assert!(!data.is_overtime(360));
+}
+
+#[test]
+fn test_all_notes() {
+    let input = "8-9 beta\n- fixed bug\n- deployed\n9-10 alpha\n- review\n10-11 gamma\n11-12 beta\n- followed up";
+    let data = parse_time_tracking_data(input, None, None);
+
+    let notes = data.all_notes();
+    let note_count: usize = data.projects.iter().map(|p| p.note_count()).sum();
+    assert_eq!(notes.len(), note_count);
+    assert_eq!(notes[0], ("alpha".to_string(), "review".to_string()));
+    assert!(notes.contains(&("beta".to_string(), "deployed".to_string())));
+    assert_eq!(
+        notes.last(),
+        Some(&("beta".to_string(), "followed up".to_string()))
+    );
+}
+
+#[test]
+fn test_lunch_break_is_not_dead_time() {
+    let input = "8-12 alpha\n1-5 beta\n5:30-6 gamma";
+    let noon = Time::new(12, 0).unwrap();
+    let one = Time::new(1, 0).unwrap();
+
+    let data = parse_time_tracking_data(input, None, None);
+    assert_eq!(data.dead_time_minutes, 90);
+
+    let config = ParserConfig::new().lunch(noon, one);
+    let data = parse_with_config(input, &config);
+    assert_eq!(data.dead_time_minutes, 30);
+    assert_eq!(data.total_minutes, 8 * 60 + 30);
+
+    // Only the part of a gap inside the break is left out
+    let data = parse_with_config("8-11:30 alpha\n1:30-5 beta", &config);
+    assert_eq!(data.dead_time_minutes, 60);
+
+    // A long gap is only flagged for the time outside the break
+    let config = ParserConfig::new().max_gap_minutes(60).lunch(noon, one);
+    let data = parse_with_config("8-11:30 alpha\n1:30-5 beta", &config);
+    assert!(data.validation_warnings.is_empty());
+    let data = parse_with_config("8-11 alpha\n1:30-5 beta", &config);
+    assert!(matches!(
+        data.validation_warnings.as_slice(),
+        [ParseWarning::LargeGap { minutes: 150, .. }]
+    ));
+}
+
+#[test]
+fn test_span_minutes() {
+    let data = parse_time_tracking_data("8-10 alpha\n11-12:30 beta\n2-3 gamma", None, None);
+    assert_eq!(data.total_minutes, 270);
+    assert_eq!(data.span_minutes(), Some(420));
+    assert_eq!(
+        data.span_minutes(),
+        Some(data.total_minutes + data.dead_time_minutes)
+    );
+
+    assert_eq!(
+        parse_time_tracking_data("", None, None).span_minutes(),
+        None
+    );
+}
+
+#[test]
+fn test_longest_focus_block() {
+    let data = parse_time_tracking_data("8-10 alpha\n10-11:30 beta\n1-4 gamma", None, None);
+    assert_eq!(
+        data.longest_focus_block(),
+        Some((Time::new(8, 0).unwrap(), Time::new(11, 30).unwrap(), 210))
+    );
+
+    // A single long entry beats shorter touching ones
+    let data = parse_time_tracking_data("8-9 alpha\n9-10 beta\n1-5 gamma", None, None);
+    assert_eq!(
+        data.longest_focus_block(),
+        Some((Time::new(1, 0).unwrap(), Time::new(5, 0).unwrap(), 240))
+    );
+
+    assert_eq!(
+        parse_time_tracking_data("", None, None).longest_focus_block(),
+        None
+    );
+}
@@ -36,7 +36,7 @@ fn test_complex_scenario_with_gaps_and_warnings() {
     assert!(
         data.warnings
             .iter()
-            .any(|w| w.contains("missing project name"))
+            .any(|w| matches!(w, ParseWarning::MissingProjectName { line, .. } if line == "3-4"))
     );
 
     // Check dead time calculation:
@@ -94,3 +94,33 @@ fn test_performance_with_large_input() {
     assert_eq!(data.projects.len(), 5); // 5 unique projects (0-4)
     assert_eq!(data.total_minutes, 100 * 60); // 100 hours
 }
+
+// Also runs without the `parallel` feature, where the option is ignored
+#[test]
+fn test_parallel_aggregation_matches_sequential() {
+    let mut input = String::new();
+    for i in 0..20_000 {
+        let start_hour = (i % 11) + 1;
+        let project = match i % 7 {
+            0 | 1 => "alpha#core".to_string(),
+            2 => "Alpha".to_string(),
+            n => format!("project{n}"),
+        };
+        input.push_str(&format!("{start_hour}-{start_hour}:30 {project}\n"));
+        input.push_str(&format!("- note {}\n", i % 13));
+    }
+
+    for order in [ProjectOrder::Alphabetical, ProjectOrder::FirstSeen] {
+        for dedup in [false, true] {
+            let config = ParserConfig::new()
+                .project_order(order)
+                .dedup_notes(dedup)
+                .project_normalization(ProjectNormalization::Lowercase);
+            let sequential = parse_with_config(&input, &config);
+            let parallel = parse_with_config(&input, &config.clone().parallel(true));
+
+            assert_eq!(parallel, sequential);
+            assert_eq!(parallel.projects.len(), 5);
+        }
+    }
+}
@@ -99,7 +99,19 @@ fn test_parse_missing_project_name() {
     let data = parse_time_tracking_data(input, None, None);
 
     assert_eq!(data.warnings.len(), 1);
-    assert!(data.warnings[0].contains("Line missing project name"));
+    assert_eq!(
+        data.warnings[0],
+        ParseWarning::MissingProjectName {
+            line_number: 1,
+            span: Some(0..3),
+            line: "7-8".to_string()
+        }
+    );
+    assert!(
+        data.warnings[0]
+            .to_string()
+            .contains("Line missing project name")
+    );
     assert_eq!(data.projects.len(), 2);
 }
 
@@ -111,7 +123,7 @@ fn test_parse_long_duration_warning() {
     let data = parse_time_tracking_data(input, None, None);
 
     // Debug: let's see what warnings we actually get
-    println!("Warnings: {:?}", data.warnings);
+    println!("Warnings: {:?}", data.validation_warnings);
     println!("Dead time minutes: {}", data.dead_time_minutes);
     for (i, entry) in data.projects.iter().enumerate() {
         println!(
@@ -128,11 +140,11 @@ fn test_parse_long_duration_warning() {
     println!("Gap from 3:00 to 1:00: {gap} minutes");
 
     // This should trigger a warning because going from 3 to 1 suggests a 22-hour gap
-    assert!(!data.warnings.is_empty());
+    assert!(!data.validation_warnings.is_empty());
     assert!(
-        data.warnings
+        data.validation_warnings
             .iter()
-            .any(|w| w.contains("longer than 6 hours"))
+            .any(|w| matches!(w, ParseWarning::LargeGap { minutes: 600, .. }))
     );
 }
 
@@ -238,19 +250,26 @@ fn test_parse_large_gap_dead_time() {
 
     println!("Debug: Total minutes: {}", data.total_minutes);
     println!("Debug: Dead time minutes: {}", data.dead_time_minutes);
-    println!("Debug: Warnings: {:?}", data.warnings);
+    println!("Debug: Warnings: {:?}", data.validation_warnings);
 
     // Total working time should be: 30 + 75 + 30 + 120 + 15 = 270 minutes (4.5 hours)
     assert_eq!(data.total_minutes, 270);
 
     // There should be a large gap from 4:00 to 3:45 (11 hours 45 minutes = 705 minutes)
     // This should both generate a warning AND be counted as dead time
-    assert!(!data.warnings.is_empty());
+    assert!(!data.validation_warnings.is_empty());
     assert!(
-        data.warnings
+        data.validation_warnings
             .iter()
-            .any(|w| w.contains("Gap from 4:00 to 3:45"))
+            .any(|w| w.to_string().contains("Gap from 4:00 to 3:45"))
     );
+    assert!(data.validation_warnings.contains(&ParseWarning::LargeGap {
+        line_number: 7,
+        from: Time::new(4, 0).unwrap(),
+        to: Time::new(3, 45).unwrap(),
+        minutes: 705,
+        limit_minutes: 360,
+    }));
 
     // The dead time should include the large gap: 705 minutes (11:45)
     assert_eq!(data.dead_time_minutes, 705);
@@ -352,3 +371,1579 @@ fn test_parse_does_not_stop_at_non_matching_line() {
     // project3 should not be included
     assert!(data.projects.iter().any(|p| p.name == "project3"));
 }
+
+#[test]
+fn test_parse_date_prefix() {
+    let input = r#"6/3 8-9 proj
+- pushed potential fix for component /build url handling
+9-10 build/deploy"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.total_minutes, 120);
+    assert_eq!(
+        data.date,
+        Some(Date {
+            year: None,
+            month: 6,
+            day: 3
+        })
+    );
+
+    let proj = data.projects.iter().find(|p| p.name == "proj").unwrap();
+    assert_eq!(proj.total_minutes, 60);
+    assert_eq!(
+        proj.notes,
+        vec!["pushed potential fix for component /build url handling".to_string()]
+    );
+    assert!(data.projects.iter().any(|p| p.name == "build/deploy"));
+}
+
+#[test]
+fn test_parse_slash_without_time_range_is_not_a_date() {
+    let input = r#"7-8 project1
+3/4 project"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    // "3/4" is not followed by a time range, so it is not treated as a date prefix
+    assert!(data.date.is_none());
+    assert_eq!(data.projects.len(), 1);
+    assert!(
+        data.warnings
+            .iter()
+            .any(|w| matches!(w, ParseWarning::InvalidTimeRange { raw, .. } if raw == "3/4"))
+    );
+}
+
+#[test]
+fn test_validate_only_matches_full_parse_warnings() {
+    let input = r#"25:70-8 project1
+7-8
+8-9 project2
+2-3 project3
+7:70-8 project4"#;
+
+    let config = ParserConfig::default();
+    let warnings = validate_only(input, &config);
+    let data = parse_with_config(input, &config);
+
+    assert!(!warnings.is_empty());
+    assert_eq!(warnings, data.all_warnings());
+
+    // Meridiem resolution and the day total are checked too
+    let config = ParserConfig::new()
+        .strict_meridiem(true)
+        .day_start_meridiem(Meridiem::Am);
+    let input = "9-11 alpha\n11-1 beta";
+    assert!(parse_with_config(input, &config).all_warnings().is_empty());
+    assert!(validate_only(input, &config).is_empty());
+
+    let input = "8-4 alpha\n4-12 beta\n12-8 gamma\n8-9 delta";
+    let data = parse_with_config(input, &ParserConfig::default());
+    assert!(
+        data.validation_warnings
+            .iter()
+            .any(|warning| matches!(warning, ParseWarning::TotalExceedsDay { .. }))
+    );
+    assert_eq!(
+        validate_only(input, &ParserConfig::default()),
+        data.all_warnings()
+    );
+}
+
+#[test]
+fn test_parse_meridiem_spanning_midnight() {
+    let input = "11:45pm-12:15am oncall";
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.total_minutes, 30);
+    let start = data.start_time.unwrap();
+    assert_eq!(start.hour, 11);
+    assert_eq!(start.meridiem, Some(Meridiem::Pm));
+    assert_eq!(data.end_time.unwrap().meridiem, Some(Meridiem::Am));
+}
+
+#[test]
+fn test_parse_mixed_meridiem_and_plain_lines() {
+    let input = r#"7:30AM-12:15P.M. project1
+12:15-1 project2
+1p-2:30p project3
+7a-8p project4"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    let minutes = |name: &str| {
+        data.projects
+            .iter()
+            .find(|p| p.name == name)
+            .unwrap()
+            .total_minutes
+    };
+    assert_eq!(minutes("project1"), 285);
+    assert_eq!(minutes("project2"), 45);
+    assert_eq!(minutes("project3"), 90);
+    // Explicit AM/PM allows blocks longer than 12 hours
+    assert_eq!(minutes("project4"), 13 * 60);
+}
+
+#[test]
+fn test_suffix_matching_note_bullet_does_not_stop_parsing() {
+    let input = r#"7-8 project1
+- first note
+- second note
+8-9 project2
+- third note
+-
+9-10 project3"#;
+
+    let data = parse_time_tracking_data(input, None, Some("-"));
+
+    // Bulleted notes don't halt parsing, but a bare "-" line is the suffix itself
+    assert_eq!(data.total_minutes, 120);
+    assert_eq!(data.projects.len(), 2);
+    let project1 = data.projects.iter().find(|p| p.name == "project1").unwrap();
+    assert_eq!(project1.notes, vec!["first note", "second note"]);
+    let project2 = data.projects.iter().find(|p| p.name == "project2").unwrap();
+    assert_eq!(project2.notes, vec!["third note"]);
+}
+
+#[test]
+fn test_note_like_suffix_stops_parsing() {
+    let input = r#"7-8 project1
+- ENDING soon, still a note
+- END
+8-9 project2"#;
+
+    let data = parse_time_tracking_data(input, None, Some("- END"));
+
+    assert_eq!(data.total_minutes, 60);
+    assert_eq!(data.projects.len(), 1);
+    assert_eq!(data.projects[0].notes, vec!["ENDING soon, still a note"]);
+}
+
+#[test]
+fn test_parse_twenty_four_hour() {
+    let config = ParserConfig {
+        twenty_four_hour: true,
+        ..Default::default()
+    };
+
+    let data = parse_with_config("09:00-17:30 project", &config);
+    assert!(
+        data.warnings
+            .iter()
+            .all(|w| !matches!(w, ParseWarning::InvalidTimeRange { .. }))
+    );
+    assert_eq!(data.total_minutes, 510);
+
+    let data = parse_with_config(
+        r#"11:30-13:00 project1
+13:30-15:00 project2
+15:00-15:45 project3"#,
+        &config,
+    );
+    assert!(data.warnings.is_empty());
+    let minutes = |name: &str| {
+        data.projects
+            .iter()
+            .find(|p| p.name == name)
+            .unwrap()
+            .total_minutes
+    };
+    assert_eq!(minutes("project1"), 90);
+    assert_eq!(minutes("project2"), 90);
+    assert_eq!(minutes("project3"), 45);
+}
+
+#[test]
+fn test_parse_mixed_clock_modes_rejected() {
+    // AM/PM markers aren't allowed in 24-hour mode
+    let config = ParserConfig {
+        twenty_four_hour: true,
+        ..Default::default()
+    };
+    let data = parse_with_config("9am-5pm project", &config);
+    assert!(data.projects.is_empty());
+    assert!(data.warnings.iter().any(
+        |w| matches!(w, ParseWarning::InvalidTimeRange { reason, .. } if reason.contains("24-hour"))
+    ));
+
+    // and 24-hour times aren't allowed in the default 12-hour mode
+    let data = parse_time_tracking_data("13:30-15:00 project", None, None);
+    assert!(data.projects.is_empty());
+    assert!(
+        data.warnings.iter().any(
+            |w| matches!(w, ParseWarning::InvalidTimeRange { raw, .. } if raw == "13:30-15:00")
+        )
+    );
+}
+
+#[test]
+fn test_try_parse_errors_on_all_invalid_input() {
+    let input = r#"Header text
+25:70-8 project1
+7:70-8 project2"#;
+
+    let err = try_parse_time_tracking_data(input, None, None).unwrap_err();
+    assert_eq!(err.line, "25:70-8 project1");
+    assert!(!err.reason.is_empty());
+
+    // The lenient parser still returns data with warnings
+    let data = parse_time_tracking_data(input, None, None);
+    assert!(data.projects.is_empty());
+    assert_eq!(data.warnings.len(), 2);
+}
+
+#[test]
+fn test_try_parse_errors_on_empty_input() {
+    let err = try_parse_time_tracking_data("no entries here", None, None).unwrap_err();
+    assert!(err.line.is_empty());
+    assert_eq!(err.reason, "No time entries found");
+}
+
+#[test]
+fn test_try_parse_succeeds_with_partial_problems() {
+    let input = r#"7-8 project1
+25:70-8 project2"#;
+
+    let data = try_parse_time_tracking_data(input, None, None).unwrap();
+    assert_eq!(data, parse_time_tracking_data(input, None, None));
+    assert_eq!(data.total_minutes, 60);
+    assert_eq!(data.warnings.len(), 1);
+}
+
+#[test]
+fn test_project_session_count() {
+    let input = r#"7-8 project1
+8-9 project1
+9-11 project2
+11-12 project1
+1-2 project2
+2:30-3 project2"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    let project1 = data.projects.iter().find(|p| p.name == "project1").unwrap();
+    assert_eq!(project1.session_count, 2); // 7-9 then interrupted, 11-12
+    let project2 = data.projects.iter().find(|p| p.name == "project2").unwrap();
+    assert_eq!(project2.session_count, 3); // 9-11, 1-2, then a gap before 2:30-3
+}
+
+#[test]
+fn test_parse_warning_variants() {
+    let input = r#"7-8
+25:70-8 project1
+8-5 project2
+2-3 project3"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+    let warnings = data.all_warnings();
+
+    assert_eq!(warnings.len(), 4);
+    assert_eq!(
+        warnings[0],
+        ParseWarning::MissingProjectName {
+            line_number: 1,
+            span: Some(0..3),
+            line: "7-8".to_string()
+        }
+    );
+    assert!(matches!(&warnings[1], ParseWarning::InvalidTimeRange { raw, .. } if raw == "25:70-8"));
+    assert_eq!(
+        warnings[2..],
+        vec![
+            ParseWarning::LongDuration {
+                line_number: 3,
+                start: Time::new(8, 0).unwrap(),
+                end: Time::new(5, 0).unwrap(),
+                minutes: 540,
+                limit_minutes: 480,
+            },
+            ParseWarning::LargeGap {
+                line_number: 4,
+                from: Time::new(5, 0).unwrap(),
+                to: Time::new(2, 0).unwrap(),
+                minutes: 540,
+                limit_minutes: 360,
+            },
+        ]
+    );
+    assert_eq!(
+        warnings[2].to_string(),
+        "Time period 8:00-5:00 appears to be longer than 8 hours. Input may not be in correct order. (line 3)"
+    );
+}
+
+#[test]
+fn test_warnings_report_source_line_numbers() {
+    let input = r#"Some header
+
+7-8 project1
+- a note
+25:70-8 project2
+
+9-10
+8-5 project3"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    let invalid = data
+        .warnings
+        .iter()
+        .find(|w| matches!(w, ParseWarning::InvalidTimeRange { .. }))
+        .unwrap();
+    assert_eq!(invalid.line_number(), 5);
+    assert!(invalid.to_string().ends_with("(line 5)"));
+
+    let missing = data
+        .warnings
+        .iter()
+        .find(|w| matches!(w, ParseWarning::MissingProjectName { .. }))
+        .unwrap();
+    assert_eq!(missing.line_number(), 7);
+
+    let long = data
+        .validation_warnings
+        .iter()
+        .find(|w| matches!(w, ParseWarning::LongDuration { .. }))
+        .unwrap();
+    assert_eq!(long.line_number(), 8);
+}
+
+#[test]
+fn test_parsed_entries_are_exposed_in_source_order() {
+    let input = r#"7:30-8 project1
+- first note
+8-9
+9-10:15 project2
+25:70-8 project3
+10:15-11 project1"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    assert_eq!(data.entries.len(), 4);
+    let summary: Vec<(Time, Time, &str)> = data
+        .entries
+        .iter()
+        .map(|e| (e.start, e.end, e.project.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                Time::new(7, 30).unwrap(),
+                Time::new(8, 0).unwrap(),
+                "project1"
+            ),
+            (
+                Time::new(8, 0).unwrap(),
+                Time::new(9, 0).unwrap(),
+                "missing"
+            ),
+            (
+                Time::new(9, 0).unwrap(),
+                Time::new(10, 15).unwrap(),
+                "project2"
+            ),
+            (
+                Time::new(10, 15).unwrap(),
+                Time::new(11, 0).unwrap(),
+                "project1"
+            ),
+        ]
+    );
+    assert_eq!(data.entries[0].notes, vec!["first note"]);
+}
+
+#[test]
+fn test_round_entries_before_aggregation() {
+    let input = r#"8-8:09 project1
+9-9:09 project1
+10-10:07 project2"#;
+
+    let config = ParserConfig {
+        round_entries_to: Some(6),
+        ..Default::default()
+    };
+    let data = parse_with_config(input, &config);
+
+    // Each 9-minute block rounds to 12, which differs from rounding the 18-minute sum (18)
+    let project1 = data.projects.iter().find(|p| p.name == "project1").unwrap();
+    assert_eq!(project1.total_minutes, 24);
+    assert_eq!(Time::round_minutes(18, 6), 18);
+
+    let project2 = data.projects.iter().find(|p| p.name == "project2").unwrap();
+    assert_eq!(project2.total_minutes, 6);
+    assert_eq!(data.total_minutes, 30);
+
+    // Dead time is never rounded
+    assert_eq!(data.dead_time_minutes, 51 + 51);
+
+    let unrounded = parse_time_tracking_data(input, None, None);
+    assert_eq!(unrounded.total_minutes, 25);
+}
+
+#[test]
+fn test_generate_sample_output() {
+    let input = r#"11:45-12:15 code1
+- Comment explaining what you did
+12:15-1:30 code2
+- Comment about what you were doing
+1:30-2 code1
+2-4 code3"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+    let output = generate_sample_output(&data);
+
+    assert_eq!(
+        output,
+        "Start Time: 11:45 End Time: 4:00
+Total Working Time: 4:15 (4.25 hrs)
+Total dead time: 0:00 (0.00 hrs)
+
+Billing Code: code1 - 1:00 (1.00 hrs)
+- Comment explaining what you did
+
+Billing Code: code2 - 1:15 (1.25 hrs)
+- Comment about what you were doing
+
+Billing Code: code3 - 2:00 (2.00 hrs)
+
+"
+    );
+}
+
+#[test]
+fn test_parse_leading_tag() {
+    let input = r#"SC 8-9 proj
+- pairing
+JD 9-10:30 proj
+SC 6/3 10:30-11 other
+11-12 untagged"#;
+
+    let config = ParserConfig {
+        leading_tag: true,
+        ..Default::default()
+    };
+    let data = parse_with_config(input, &config);
+
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.entries[0].tag.as_deref(), Some("SC"));
+    assert_eq!(data.entries[0].project, "proj");
+    assert_eq!(data.entries[0].notes, vec!["pairing"]);
+    assert_eq!(data.entries[2].tag.as_deref(), Some("SC"));
+    assert_eq!(data.entries[2].date.unwrap().day, 3);
+    assert_eq!(data.entries[3].tag, None);
+
+    let proj = data.projects.iter().find(|p| p.name == "proj").unwrap();
+    assert_eq!(proj.total_minutes, 150);
+
+    let by_tag = data.minutes_by_tag();
+    assert_eq!(by_tag.len(), 2);
+    assert_eq!(by_tag["SC"], 90);
+    assert_eq!(by_tag["JD"], 90);
+
+    // Without the option the tagged lines aren't recognized as entries
+    let data = parse_time_tracking_data(input, None, None);
+    assert!(data.entries.iter().all(|e| e.tag.is_none()));
+    assert_ne!(data.total_minutes, 270);
+}
+
+#[test]
+fn test_parse_next_day_marker() {
+    let data = parse_time_tracking_data("10-2+1d oncall", None, None);
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.total_minutes, 240);
+    assert_eq!(data.start_time.unwrap().meridiem, Some(Meridiem::Pm));
+    assert_eq!(data.end_time.unwrap().meridiem, Some(Meridiem::Am));
+
+    // The heuristic can't express an overnight block longer than 12 hours
+    let data = parse_time_tracking_data("6-7+1 night-shift", None, None);
+    assert_eq!(data.total_minutes, 13 * 60);
+    assert_eq!(
+        parse_time_tracking_data("6-7 night-shift", None, None).total_minutes,
+        60
+    );
+
+    // Explicit AM/PM still wins
+    let data = parse_time_tracking_data("10am-2am+1d oncall", None, None);
+    assert_eq!(data.total_minutes, 16 * 60);
+}
+
+#[test]
+fn test_validation_thresholds_are_configurable() {
+    let input = "8-5 night-shift\n5-6 handover";
+    let long_entries = |config: &ParserConfig| {
+        parse_with_config(input, config)
+            .validation_warnings
+            .iter()
+            .filter(|w| matches!(w, ParseWarning::LongDuration { .. }))
+            .count()
+    };
+
+    // A 9-hour block is flagged under the default 8-hour threshold...
+    assert_eq!(long_entries(&ParserConfig::default()), 1);
+
+    // ...but not when the threshold is raised to 10 hours
+    let relaxed = ParserConfig {
+        validation: ValidationConfig {
+            max_entry_minutes: 10 * 60,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    assert_eq!(long_entries(&relaxed), 0);
+
+    // Lowering the threshold flags shorter blocks, and the message names the threshold
+    let strict = ParserConfig {
+        validation: ValidationConfig {
+            max_entry_minutes: 90,
+            max_gap_minutes: 30,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let data = parse_with_config("8-3 focus", &strict);
+    assert_eq!(
+        data.validation_warnings[0].to_string(),
+        "Time period 8:00-3:00 appears to be longer than 90 minutes. Input may not be in correct order. (line 1)"
+    );
+}
+
+#[test]
+fn test_parse_mixed_ranges_and_durations() {
+    let input = r#"8-9 standup
+- sprint planning
+1h5m review
+- PR feedback
+9-10:30 coding
+30m standup"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.total_minutes, 60 + 65 + 90 + 30);
+    assert_eq!(data.entries.len(), 4);
+    assert_eq!(data.entries[1].duration, Some(65));
+
+    // Durations aggregate into the same projects as clock ranges
+    let standup = data.projects.iter().find(|p| p.name == "standup").unwrap();
+    assert_eq!(standup.total_minutes, 90);
+    let review = data.projects.iter().find(|p| p.name == "review").unwrap();
+    assert_eq!(review.notes, vec!["PR feedback"]);
+
+    // Only the clocked entries place the day on the clock
+    assert_eq!(data.start_time, Some(Time::new(8, 0).unwrap()));
+    assert_eq!(data.end_time, Some(Time::new(10, 30).unwrap()));
+    assert_eq!(data.dead_time_minutes, 0);
+}
+
+#[test]
+fn test_zero_duration_warning() {
+    let data = parse_time_tracking_data("7-8 other\n8-8 project", None, None);
+
+    let zero: Vec<&ParseWarning> = data
+        .validation_warnings
+        .iter()
+        .filter(|w| matches!(w, ParseWarning::ZeroDuration { .. }))
+        .collect();
+    assert_eq!(zero.len(), 1);
+    assert_eq!(
+        zero[0].to_string(),
+        "Entry 8:00-8:00 has zero duration (line 2)"
+    );
+}
+
+#[test]
+fn test_configurable_note_prefixes() {
+    let input = r#"8-9 project1
+• reviewed the design
+> follow up with ops
+9-10 project2
+- dash is not a marker here"#;
+
+    let config = ParserConfig {
+        note_prefixes: vec!["•".to_string(), ">".to_string()],
+        ..Default::default()
+    };
+    let data = parse_with_config(input, &config);
+
+    assert_eq!(data.entries.len(), 2);
+    assert_eq!(
+        data.entries[0].notes,
+        vec!["reviewed the design", "follow up with ops"]
+    );
+    assert_eq!(data.entries[1].notes, vec!["- dash is not a marker here"]);
+}
+
+#[test]
+fn test_max_line_length_skips_long_lines() {
+    let input = format!("8-9 project1\n{}\n9-10 project2\n- note", "x".repeat(5000));
+
+    let config = ParserConfig {
+        max_line_length: Some(200),
+        ..Default::default()
+    };
+    let data = parse_with_config(&input, &config);
+
+    assert_eq!(
+        data.warnings,
+        vec![ParseWarning::LineTooLong {
+            line_number: 2,
+            span: Some(13..5013),
+            length: 5000,
+            limit: 200,
+        }]
+    );
+    assert_eq!(data.total_minutes, 120);
+    assert!(data.entries[0].notes.is_empty());
+    assert_eq!(data.entries[1].notes, vec!["note"]);
+}
+
+#[test]
+fn test_split_projects() {
+    let config = ParserConfig {
+        split_projects: true,
+        ..Default::default()
+    };
+    let minutes = |data: &TimeTrackingData, name: &str| {
+        data.projects
+            .iter()
+            .find(|p| p.name == name)
+            .unwrap()
+            .total_minutes
+    };
+
+    let data = parse_with_config("8-11 a b", &config);
+    assert_eq!(minutes(&data, "a"), 90);
+    assert_eq!(minutes(&data, "b"), 90);
+    assert_eq!(data.entries[1].start, Time::new(9, 30).unwrap());
+    assert_eq!(data.end_time, Some(Time::new(11, 0).unwrap()));
+
+    let data = parse_with_config("8-10 a b c", &config);
+    assert_eq!(data.total_minutes, 120);
+    assert_eq!(data.projects.len(), 3);
+
+    // The remainder of an uneven split goes to the first project
+    let data = parse_with_config("8-9:40 a b c\n- shared note", &config);
+    assert_eq!(minutes(&data, "a"), 34);
+    assert_eq!(minutes(&data, "b"), 33);
+    assert_eq!(minutes(&data, "c"), 33);
+    assert_eq!(data.total_minutes, 100);
+    assert_eq!(data.dead_time_minutes, 0);
+
+    // Without the option the tokens stay a single project name
+    let data = parse_time_tracking_data("8-10 a b", None, None);
+    assert_eq!(data.projects[0].name, "a b");
+}
+
+#[test]
+fn test_case_insensitive_project_aggregation() {
+    let input = r#"8-9 Admin
+- email
+9-10 coding
+10-10:30 admin
+- timesheets"#;
+
+    let default = parse_time_tracking_data(input, None, None);
+    assert_eq!(default.projects.len(), 3);
+
+    let config = ParserConfig {
+        project_normalization: Some(ProjectNormalization::Lowercase),
+        ..Default::default()
+    };
+    let data = parse_with_config(input, &config);
+
+    assert_eq!(data.projects.len(), 2);
+    let admin = data.project("Admin").unwrap();
+    assert_eq!(admin.total_minutes, 90);
+    assert_eq!(admin.notes, vec!["email", "timesheets"]);
+    assert_eq!(admin.session_count, 2);
+    assert!(data.project("admin").is_none());
+
+    assert_eq!(
+        ProjectNormalization::TrimLowercase.normalize("  Big   Project "),
+        "big project"
+    );
+}
+
+#[test]
+fn test_dedup_notes() {
+    let input = r#"8-9 project1
+- standup
+- fixed login bug
+9-10 project1
+- standup
+- code review"#;
+
+    let default = parse_time_tracking_data(input, None, None);
+    assert_eq!(default.projects[0].notes.len(), 4);
+
+    let config = ParserConfig {
+        dedup_notes: true,
+        ..Default::default()
+    };
+    let data = parse_with_config(input, &config);
+    assert_eq!(
+        data.projects[0].notes,
+        vec!["standup", "fixed login bug", "code review"]
+    );
+    // Entries keep their own notes untouched
+    assert_eq!(data.entries[1].notes, vec!["standup", "code review"]);
+}
+
+#[test]
+fn test_range_separators() {
+    let expected =
+        parse_time_tracking_data("8-9:30 project1\n- note\n9:30-10 project2", None, None);
+
+    for input in [
+        "8\u{2013}9:30 project1\n- note\n9:30\u{2013}10 project2",
+        "8 \u{2014} 9:30 project1\n- note\n9:30\u{2014}10 project2",
+        "8 to 9:30 project1\n- note\n9:30 to 10 project2",
+    ] {
+        let data = parse_time_tracking_data(input, None, None);
+        assert!(data.warnings.is_empty(), "{input}");
+        assert_eq!(data, expected, "{input}");
+    }
+}
+
+#[test]
+fn test_parse_duration_only_entries() {
+    let input = r#"30m standup
+1h coding
+- refactored parser
+1h30m review
+45m"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    assert_eq!(data.entries.len(), 4);
+    assert_eq!(data.project("review").unwrap().total_minutes, 90);
+    assert_eq!(data.project("standup").unwrap().total_minutes, 30);
+    assert_eq!(
+        data.project("coding").unwrap().notes,
+        vec!["refactored parser"]
+    );
+    assert_eq!(data.total_minutes, 30 + 60 + 90 + 45);
+    assert!(data.entries.iter().all(|entry| !entry.is_clocked()));
+
+    // Nothing was on the clock, so there's no start/end or dead time
+    assert_eq!(data.start_time, None);
+    assert_eq!(data.end_time, None);
+    assert_eq!(data.dead_time_minutes, 0);
+
+    // A duration with no project is reported like any other unnamed entry
+    assert!(matches!(
+        data.warnings.as_slice(),
+        [ParseWarning::MissingProjectName { line_number: 5, .. }]
+    ));
+}
+
+#[test]
+fn test_parse_multi_day() {
+    let input = r#"Week of March 4th
+
+2024-03-04
+8-9 projectA
+- planning
+9-12 projectB
+
+2024-03-05
+8-10 projectA
+10-10:30
+"#;
+
+    let days = parse_multi_day(input);
+    assert_eq!(days.len(), 2);
+
+    let (monday, first) = &days[0];
+    assert_eq!(*monday, Some(Date::new(Some(2024), 3, 4).unwrap()));
+    assert_eq!(first.total_minutes, 240);
+    assert_eq!(first.date, *monday);
+    assert_eq!(first.entries[0].date, *monday);
+    assert_eq!(first.project("projectA").unwrap().notes, vec!["planning"]);
+
+    let (tuesday, second) = &days[1];
+    assert_eq!(*tuesday, Some(Date::new(Some(2024), 3, 5).unwrap()));
+    assert_eq!(second.total_minutes, 150);
+    assert_eq!(second.project("projectA").unwrap().total_minutes, 120);
+    // Line numbers refer to the whole input
+    assert_eq!(second.entries[0].line_number, 9);
+    assert_eq!(second.warnings[0].line_number(), 10);
+}
+
+#[test]
+fn test_parse_multi_day_entries_before_first_header() {
+    let days = parse_multi_day("7-8 early\n2024-03-04\n8-9 projectA");
+
+    assert_eq!(days.len(), 2);
+    assert_eq!(days[0].0, None);
+    assert_eq!(days[0].1.projects[0].name, "early");
+    assert_eq!(days[1].1.total_minutes, 60);
+}
+
+#[test]
+fn test_parse_whitespace_separated_entries() {
+    let expected = parse_time_tracking_data("8-9 projectA\n9-10:30 projectB", None, None);
+
+    for input in [
+        "8-9\tprojectA\n9-10:30\tprojectB",
+        "8-9    projectA\n9-10:30 \t projectB",
+    ] {
+        let data = parse_time_tracking_data(input, None, None);
+        assert!(data.warnings.is_empty(), "{input:?}");
+        assert_eq!(data.projects, expected.projects, "{input:?}");
+    }
+}
+
+#[test]
+fn test_parse_inline_note() {
+    let input = r#"8-9 project: fixed the deploy script
+- tested on staging
+9-10 project"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+
+    assert_eq!(data.entries[0].project, "project");
+    assert_eq!(
+        data.entries[0].notes,
+        vec!["fixed the deploy script", "tested on staging"]
+    );
+    assert!(data.entries[1].notes.is_empty());
+    assert_eq!(data.projects.len(), 1);
+    assert_eq!(data.projects[0].total_minutes, 120);
+}
+
+#[test]
+fn test_parser_config_builder() {
+    let config = ParserConfig::new()
+        .prefix("```timetracking")
+        .suffix("```")
+        .max_gap_minutes(60)
+        .note_prefixes(["•"]);
+
+    assert_eq!(config.prefix.as_deref(), Some("```timetracking"));
+    assert_eq!(config.validation.max_gap_minutes, 60);
+    assert_eq!(config.validation.max_entry_minutes, 8 * 60);
+
+    let input = r#"Some notes
+```timetracking
+8-9 project1
+• planning
+11-12 project2
+```
+Trailing text"#;
+
+    let data = parse_with_config(input, &config);
+    assert_eq!(data.total_minutes, 120);
+    assert_eq!(data.entries[0].notes, vec!["planning"]);
+    assert!(matches!(
+        data.validation_warnings.as_slice(),
+        [ParseWarning::LargeGap { minutes: 120, .. }]
+    ));
+}
+
+#[test]
+fn test_parse_from_reader_matches_string_parse() {
+    let input = "Header\r\n```timetracking\r\n7:30-8 someproject\r\n- note one\r\n8-8:30\r\n25:70-9 broken\r\n9-10 admin\r\n```\r\ntrailing";
+
+    let from_reader = parse_from_reader(
+        std::io::Cursor::new(input.as_bytes()),
+        Some("```timetracking"),
+        Some("```"),
+    )
+    .unwrap();
+    let from_str = parse_time_tracking_data(input, Some("```timetracking"), Some("```"));
+
+    assert_eq!(from_reader, from_str);
+    assert_eq!(from_reader.total_minutes, 120);
+}
+
+#[test]
+fn test_entries_iter_matches_full_parse() {
+    let input = r#"Header
+7:30-8 someproject
+- first note
+8-8:30 admin
+25:70-9 broken
+9-10 someproject
+* second note
+```
+10-11 after-suffix"#;
+
+    let results: Vec<Result<TimeEntry, ParseError>> =
+        entries_iter(input, None, Some("```")).collect();
+    let data = parse_time_tracking_data(input, None, Some("```"));
+
+    assert_eq!(results.len(), 4);
+    assert!(results[2].is_err());
+
+    let entries: Vec<TimeEntry> = results.into_iter().filter_map(Result::ok).collect();
+    assert_eq!(entries, data.entries);
+    assert_eq!(entries[2].notes, vec!["second note"]);
+}
+
+#[test]
+fn test_entries_out_of_order() {
+    let data = parse_time_tracking_data("10-11 a\n8-9 b", None, None);
+
+    let out_of_order: Vec<&ParseWarning> = data
+        .validation_warnings
+        .iter()
+        .filter(|w| matches!(w, ParseWarning::EntriesOutOfOrder { .. }))
+        .collect();
+    assert_eq!(
+        out_of_order,
+        vec![&ParseWarning::EntriesOutOfOrder {
+            line_number: 2,
+            previous_start: Time::new(10, 0).unwrap(),
+            start: Time::new(8, 0).unwrap(),
+        }]
+    );
+    assert_eq!(
+        out_of_order[0].to_string(),
+        "Entry starting at 8:00 begins before the previous entry at 10:00. Input may not be in correct order. (line 2)"
+    );
+
+    // Crossing noon is not out of order, but stepping back after it is
+    let afternoon = parse_time_tracking_data("10-12 a\n12-1 b\n1:30-3 c\n1-2 d", None, None);
+    let lines: Vec<usize> = afternoon
+        .validation_warnings
+        .iter()
+        .filter(|w| matches!(w, ParseWarning::EntriesOutOfOrder { .. }))
+        .map(ParseWarning::line_number)
+        .collect();
+    assert_eq!(lines, vec![4]);
+}
+
+#[test]
+fn test_strict_meridiem_flags_ambiguous_crossings() {
+    let input = "9-11 morning\n11-1 project\n1-2 afternoon\n2pm-3pm explicit";
+
+    let relaxed = parse_time_tracking_data(input, None, None);
+    assert!(relaxed.validation_warnings.is_empty());
+
+    let strict = parse_with_config(input, &ParserConfig::new().strict_meridiem(true));
+    assert_eq!(
+        strict.validation_warnings,
+        vec![ParseWarning::AmbiguousTime {
+            line_number: 2,
+            from: Time::new(11, 0).unwrap(),
+            to: Time::new(1, 0).unwrap(),
+        }]
+    );
+
+    // Ending exactly at noon isn't a guess
+    let noon = parse_with_config(
+        "11-12 project\n12-1 lunch",
+        &ParserConfig::new().strict_meridiem(true),
+    );
+    assert!(noon.validation_warnings.is_empty());
+}
+
+#[test]
+fn test_parse_noon_and_midnight() {
+    let data = parse_time_tracking_data("11-noon project\nNoon-1 lunch", None, None);
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.entries[0].duration_minutes(), 60);
+    assert_eq!(data.entries[1].duration_minutes(), 60);
+    assert_eq!(data.total_minutes, 120);
+    assert_eq!(
+        data.entries[0].end,
+        Time::new(12, 0).unwrap().with_meridiem(Meridiem::Pm)
+    );
+
+    let late = parse_time_tracking_data("10pm-midnight deploy\nmidnight-1 on-call", None, None);
+    assert!(late.warnings.is_empty());
+    assert_eq!(late.entries[0].duration_minutes(), 120);
+    assert_eq!(late.entries[1].duration_minutes(), 60);
+    assert_eq!(late.entries[1].project, "on-call");
+}
+
+#[test]
+fn test_open_ended_last_entry() {
+    let input = "1-2 review\n2- meeting\n- planning";
+
+    let config = ParserConfig::new().now(Time::new(3, 0).unwrap());
+    let data = parse_with_config(input, &config);
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.entries.len(), 2);
+    assert_eq!(data.entries[1].project, "meeting");
+    assert_eq!(data.entries[1].notes, vec!["planning"]);
+    assert_eq!(data.entries[1].duration_minutes(), 60);
+    assert_eq!(data.total_minutes, 120);
+
+    // Without a current time the open-ended entry is skipped
+    let data = parse_time_tracking_data(input, None, None);
+    assert_eq!(data.entries.len(), 1);
+    assert_eq!(data.total_minutes, 60);
+    assert!(matches!(
+        data.warnings.as_slice(),
+        [ParseWarning::OpenEndedEntry { line_number: 2, .. }]
+    ));
+}
+
+#[test]
+fn test_open_ended_entry_is_finished_like_other_ranges() {
+    let config = ParserConfig::new()
+        .now(Time::new(4, 0).unwrap())
+        .split_projects(true);
+    let data = parse_with_config("2- alpha beta#core\n- sync", &config);
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.entries.len(), 2);
+    assert_eq!(data.entries[0].project, "alpha");
+    assert_eq!(data.entries[0].end, Time::new(3, 0).unwrap());
+    assert_eq!(data.entries[1].project, "beta");
+    assert_eq!(data.entries[1].tags, vec!["core"]);
+    assert_eq!(data.entries[1].notes, vec!["sync"]);
+    assert_eq!(data.total_minutes, 120);
+
+    // Split entries are dropped with the open-ended entry when it isn't last
+    let data = parse_with_config("2- alpha beta\n3-4 review", &config);
+    assert_eq!(data.entries.len(), 1);
+    assert_eq!(data.entries[0].project, "review");
+
+    let config = ParserConfig::new().now(Time::new(1, 0).unwrap().with_meridiem(Meridiem::Am));
+    let data = parse_with_config("11pm- oncall", &config);
+    assert!(data.entries[0].crosses_midnight);
+}
+
+#[test]
+fn test_open_ended_entry_mid_log_warns() {
+    let config = ParserConfig::new().now(Time::new(5, 0).unwrap());
+    let data = parse_with_config("1- meeting\n2-3 review", &config);
+
+    assert_eq!(data.entries.len(), 1);
+    assert_eq!(data.entries[0].project, "review");
+    assert!(matches!(
+        data.warnings.as_slice(),
+        [ParseWarning::OpenEndedEntry { line_number: 1, .. }]
+    ));
+}
+
+#[test]
+fn test_parse_seconds() {
+    let input = "7:30:15-8:00:45 alpha\n8:00:45-9 beta\n9-9:30 gamma";
+    let data = parse_time_tracking_data(input, None, None);
+
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.entries.len(), 3);
+    assert_eq!(data.entries[0].start.second.unwrap().get(), 15);
+    assert_eq!(data.entries[0].duration_seconds(), 30 * 60 + 30);
+    assert_eq!(data.entries[1].duration_seconds(), 59 * 60 + 15);
+    assert_eq!(data.entries[2].start.second, None);
+
+    // Totals stay minute-based, with the exact figure in total_seconds
+    assert_eq!(data.total_minutes, 120);
+    assert_eq!(data.total_seconds(), 2 * 3600 - 15);
+
+    let data = parse_with_config(
+        "13:00:10-13:00:40 standup",
+        &ParserConfig::new().twenty_four_hour(true),
+    );
+    assert_eq!(data.entries[0].duration_seconds(), 30);
+}
+
+#[test]
+fn test_project_tags() {
+    let input = "8-9 api#backend\n- fixed auth\n9-10 web\n10-11 api#infra#backend\n11-12 C#";
+    let data = parse_time_tracking_data(input, None, None);
+
+    assert_eq!(data.entries[0].project, "api");
+    assert_eq!(data.entries[0].tags, vec!["backend"]);
+    assert_eq!(data.entries[2].tags, vec!["infra", "backend"]);
+    // A trailing '#' with no tag is part of the name
+    assert_eq!(data.entries[3].project, "C#");
+    assert!(data.entries[3].tags.is_empty());
+
+    let api = data.project("api").unwrap();
+    assert_eq!(api.total_minutes, 120);
+    assert_eq!(api.tags, vec!["backend", "infra"]);
+    assert!(data.project("web").unwrap().tags.is_empty());
+
+    // Tags are written back out by to_tracking_text
+    let reparsed = parse_time_tracking_data(&data.to_tracking_text(), None, None);
+    assert_eq!(reparsed.projects, data.projects);
+}
+
+#[test]
+fn test_parse_decimal_hours() {
+    let data = parse_time_tracking_data("1.5 standup\n- sync\n0.25 email\n.5 review", None, None);
+
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.entries.len(), 3);
+    assert_eq!(data.entries[0].duration, Some(90));
+    assert_eq!(data.entries[0].notes, vec!["sync"]);
+    assert_eq!(data.entries[1].duration_minutes(), 15);
+    assert_eq!(data.entries[1].project, "email");
+    assert_eq!(data.entries[2].duration_minutes(), 30);
+    assert_eq!(data.total_minutes, 135);
+
+    // More than a day's worth of hours is rejected
+    let data = parse_time_tracking_data("8-9 alpha\n30.5 typo", None, None);
+    assert_eq!(data.total_minutes, 60);
+    assert!(matches!(
+        data.warnings.as_slice(),
+        [ParseWarning::InvalidTimeRange { line_number: 2, .. }]
+    ));
+}
+
+const PROSE_LOG: &str = "8-9 alpha
+- fixed bug
+tech connect
+9-10 beta
+```
+10-11 gamma";
+
+#[test]
+fn test_stop_mode_at_suffix() {
+    let config = ParserConfig::new().suffix("```");
+    assert_eq!(config.stop_mode, StopMode::AtSuffix);
+    let data = parse_with_config(PROSE_LOG, &config);
+
+    // Prose is kept as a note and parsing stops at the suffix
+    assert_eq!(data.entries.len(), 2);
+    assert_eq!(data.entries[0].notes, vec!["fixed bug", "tech connect"]);
+    assert!(data.warnings.is_empty());
+}
+
+#[test]
+fn test_stop_mode_at_non_matching_line() {
+    let config = ParserConfig::new()
+        .suffix("```")
+        .stop_mode(StopMode::AtNonMatchingLine);
+    let data = parse_with_config(PROSE_LOG, &config);
+
+    assert_eq!(data.entries.len(), 1);
+    assert_eq!(data.entries[0].notes, vec!["fixed bug"]);
+    assert_eq!(data.total_minutes, 60);
+}
+
+#[test]
+fn test_stop_mode_never() {
+    let config = ParserConfig::new().suffix("```").stop_mode(StopMode::Never);
+    let data = parse_with_config(PROSE_LOG, &config);
+
+    assert_eq!(data.entries.len(), 3);
+    assert_eq!(data.entries[0].notes, vec!["fixed bug"]);
+    assert_eq!(data.total_minutes, 180);
+    assert_eq!(
+        data.warnings,
+        vec![
+            ParseWarning::UnrecognizedLine {
+                line_number: 3,
+                span: Some(22..34),
+                line: "tech connect".to_string(),
+            },
+            ParseWarning::UnrecognizedLine {
+                line_number: 5,
+                span: Some(45..48),
+                line: "```".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_indented_sub_notes() {
+    let input = "8-9 alpha: kickoff
+- task
+  - subtask one
+\t- subtask two
+- another task
+9-10 beta
+- flat note";
+    let data = parse_time_tracking_data(input, None, None);
+
+    let alpha = &data.entries[0];
+    assert_eq!(
+        alpha.notes,
+        vec![
+            "kickoff",
+            "task",
+            "subtask one",
+            "subtask two",
+            "another task"
+        ]
+    );
+    assert_eq!(alpha.note_depths, vec![0, 0, 1, 1, 0]);
+    assert_eq!(
+        alpha.notes_tree(),
+        vec![
+            NoteNode {
+                text: "kickoff".to_string(),
+                children: vec![],
+            },
+            NoteNode {
+                text: "task".to_string(),
+                children: vec![
+                    NoteNode {
+                        text: "subtask one".to_string(),
+                        children: vec![],
+                    },
+                    NoteNode {
+                        text: "subtask two".to_string(),
+                        children: vec![],
+                    },
+                ],
+            },
+            NoteNode {
+                text: "another task".to_string(),
+                children: vec![],
+            },
+        ]
+    );
+
+    // Flat notes don't record depths
+    assert!(data.entries[1].note_depths.is_empty());
+    assert_eq!(data.entries[1].notes_tree().len(), 1);
+
+    let text = data.to_tracking_text();
+    assert!(text.contains("- task\n  - subtask one\n  - subtask two\n- another task\n"));
+}
+
+#[test]
+fn test_comment_lines_are_skipped() {
+    let input = "8-9 api#backend
+- fixed auth
+# lunch was here
+- wrote tests
+#9-10 skipped
+9-10 web
+# done for the day";
+    let config = ParserConfig::new().stop_mode(StopMode::AtNonMatchingLine);
+    let data = parse_with_config(input, &config);
+
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.entries.len(), 2);
+    assert_eq!(data.entries[0].tags, vec!["backend"]);
+    assert_eq!(data.entries[0].notes, vec!["fixed auth", "wrote tests"]);
+    assert!(data.entries[1].notes.is_empty());
+    assert_eq!(data.total_minutes, 120);
+
+    // With comments disabled the line is an ordinary note
+    let data = parse_with_config(input, &ParserConfig::new().comment_prefix(None));
+    assert_eq!(
+        data.entries[0].notes,
+        vec![
+            "fixed auth",
+            "# lunch was here",
+            "wrote tests",
+            "#9-10 skipped"
+        ]
+    );
+}
+
+#[test]
+fn test_project_order_first_seen() {
+    let input = "8-9 zeta\n9-10 alpha\n10-11 mid\n11-12 zeta";
+    let names = |data: &TimeTrackingData| -> Vec<String> {
+        data.projects.iter().map(|p| p.name.clone()).collect()
+    };
+
+    let data = parse_with_config(input, &ParserConfig::new());
+    assert_eq!(names(&data), vec!["alpha", "mid", "zeta"]);
+
+    let config = ParserConfig::new().project_order(ProjectOrder::FirstSeen);
+    let data = parse_with_config(input, &config);
+    assert_eq!(names(&data), vec!["zeta", "alpha", "mid"]);
+    assert_eq!(data.projects[0].total_minutes, 120);
+}
+
+#[test]
+fn test_multiple_ranges_on_one_line() {
+    let data = parse_time_tracking_data("8-9, 2-3 review\n- second pass\n3-3:30 admin", None, None);
+
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.entries.len(), 3);
+    assert_eq!(data.entries[0].project, "review");
+    assert!(data.entries[0].notes.is_empty());
+    assert_eq!(data.entries[1].start, Time::new(2, 0).unwrap());
+    assert_eq!(data.entries[1].notes, vec!["second pass"]);
+    assert_eq!(data.project("review").unwrap().total_minutes, 120);
+    assert_eq!(data.project("review").unwrap().session_count, 2);
+
+    let data = parse_time_tracking_data("8-9,10-10:30,1-2 review#qa", None, None);
+    assert_eq!(data.entries.len(), 3);
+    assert_eq!(data.total_minutes, 150);
+    assert!(data.entries.iter().all(|entry| entry.tags == ["qa"]));
+
+    // Each range is validated on its own
+    let data = parse_time_tracking_data("8-99, 9-10 review", None, None);
+    assert_eq!(data.total_minutes, 60);
+    assert!(matches!(
+        data.warnings.as_slice(),
+        [ParseWarning::InvalidTimeRange { line_number: 1, .. }]
+    ));
+}
+
+#[test]
+fn test_day_start_meridiem() {
+    let input = "9-11 alpha\n11-1 beta\n1:30-5 gamma\n11-1 oncall";
+    let strict = ParserConfig::new().strict_meridiem(true);
+
+    let guessed = parse_with_config(input, &strict);
+    let config = strict.clone().day_start_meridiem(Meridiem::Am);
+    let resolved = parse_with_config(input, &config);
+
+    // Totals agree, but only the resolved parse knows where noon and midnight fall
+    assert_eq!(resolved.total_minutes, guessed.total_minutes);
+    assert_eq!(resolved.total_minutes, 2 * 60 + 2 * 60 + 210 + 2 * 60);
+    assert_eq!(resolved.dead_time_minutes, guessed.dead_time_minutes);
+    assert!(
+        guessed
+            .validation_warnings
+            .iter()
+            .any(|warning| matches!(warning, ParseWarning::AmbiguousTime { .. }))
+    );
+    assert!(resolved.validation_warnings.is_empty());
+
+    let pm = |hour, minute| Time::new(hour, minute).unwrap().with_meridiem(Meridiem::Pm);
+    let am = |hour, minute| Time::new(hour, minute).unwrap().with_meridiem(Meridiem::Am);
+    assert_eq!(resolved.entries[1].start, am(11, 0));
+    assert_eq!(resolved.entries[1].end, pm(1, 0));
+    assert_eq!(resolved.entries[3].start, pm(11, 0));
+    assert_eq!(resolved.entries[3].end, am(1, 0));
+    assert_eq!(resolved.end_time, Some(am(1, 0)));
+}
+
+#[test]
+fn test_warning_spans_slice_input() {
+    let input = "Notes for today\r\n7:30-8 alpha\r\n  SC 6/3 25:70-8 beta\r\n8-9\r\n9 to 9:99 gamma\n10- delta\n11-12 epsilon";
+    let config = ParserConfig::new().leading_tag(true);
+    let data = parse_with_config(input, &config);
+
+    let spanned: Vec<&str> = data
+        .warnings
+        .iter()
+        .filter_map(|warning| warning.span())
+        .map(|span| &input[span])
+        .collect();
+    // The rewritten "9 to 9:99" range still points at its own text
+    assert_eq!(spanned, vec!["25:70-8", "8-9", "9 to 9:99", "10-"]);
+
+    // Readers track offsets too, and validation warnings never have spans
+    let from_reader = parse_from_reader_with_config(input.as_bytes(), &config).unwrap();
+    assert_eq!(from_reader.warnings, data.warnings);
+    let data = parse_time_tracking_data("8-8 alpha", None, None);
+    assert_eq!(data.validation_warnings[0].span(), None);
+
+    // Multi-day sections keep offsets into the whole input
+    let input = "2024-03-04\n8-9 alpha\n2024-03-05\n8-99 beta";
+    let days = parse_multi_day(input);
+    let span = days[1].1.warnings[0].span().unwrap();
+    assert_eq!(&input[span], "8-99");
+}
+
+#[test]
+fn test_warning_spans_point_at_the_offending_token() {
+    let spans = |input: &str| -> Vec<std::ops::Range<usize>> {
+        parse_time_tracking_data(input, None, None)
+            .warnings
+            .iter()
+            .filter_map(|warning| warning.span())
+            .collect()
+    };
+
+    // A repeated token points at its own occurrence, not the first one in the line
+    assert_eq!(spans("9-99, 9-99 review"), vec![0..4, 6..10]);
+    assert_eq!(spans("8-8:30 a\n8/8 8-99 b"), vec![13..17]);
+
+    // Ranges written with "to" or a dash cover the original separator
+    let input = "8 to 8:99 review\n9\u{2013}9:99 review";
+    let spanned: Vec<&str> = spans(input).into_iter().map(|span| &input[span]).collect();
+    assert_eq!(spanned, vec!["8 to 8:99", "9\u{2013}9:99"]);
+}
+
+#[test]
+fn test_parse_fraction_glyph_hours() {
+    let data = parse_time_tracking_data("1½ review\n¾ email\n2¼ planning", None, None);
+
+    assert!(data.warnings.is_empty());
+    assert_eq!(data.entries[0].duration, Some(90));
+    assert_eq!(data.entries[0].project, "review");
+    assert_eq!(data.entries[1].duration, Some(45));
+    assert_eq!(data.entries[2].duration, Some(135));
+    assert_eq!(data.total_minutes, 270);
+}
+
+#[test]
+fn test_parse_and_validation_warnings_are_separate() {
+    let data = parse_time_tracking_data("8-9\n9-10 alpha\n25:70-8 beta\n10-10 gamma", None, None);
+
+    assert!(matches!(
+        data.warnings.as_slice(),
+        [
+            ParseWarning::MissingProjectName { line_number: 1, .. },
+            ParseWarning::InvalidTimeRange { line_number: 3, .. },
+        ]
+    ));
+    assert!(matches!(
+        data.validation_warnings.as_slice(),
+        [ParseWarning::ZeroDuration { line_number: 4, .. }]
+    ));
+
+    let all = data.all_warnings();
+    assert_eq!(all.len(), 3);
+    assert_eq!(all[..2], data.warnings[..]);
+    assert_eq!(all[2..], data.validation_warnings[..]);
+    assert_eq!(
+        validate_only(
+            "8-9\n9-10 alpha\n25:70-8 beta\n10-10 gamma",
+            &ParserConfig::new()
+        ),
+        all
+    );
+}
+
+#[test]
+fn test_parse_leading_zero_hours() {
+    let data = parse_time_tracking_data("07:30-08:00 alpha\n08:00-09:15 beta", None, None);
+
+    assert!(data.all_warnings().is_empty());
+    assert_eq!(data.entries[0].start, Time::new(7, 30).unwrap());
+    assert_eq!(data.entries[1].end, Time::new(9, 15).unwrap());
+    assert_eq!(data.total_minutes, 105);
+
+    // "00:30" is half past midnight, so it runs on from a late evening entry
+    let data = parse_time_tracking_data("11pm-00:30 oncall", None, None);
+    assert!(data.all_warnings().is_empty());
+    assert_eq!(data.total_minutes, 90);
+    assert_eq!(
+        data.entries[0].end,
+        Time::new(12, 30).unwrap().with_meridiem(Meridiem::Am)
+    );
+}
+
+#[test]
+fn test_parse_entry_line() {
+    let entry = parse_entry_line("8:30-10 api#backend: fixed the deploy").unwrap();
+    assert_eq!(entry.start, Time::new(8, 30).unwrap());
+    assert_eq!(entry.end, Time::new(10, 0).unwrap());
+    assert_eq!(entry.project, "api");
+    assert_eq!(entry.tags, vec!["backend"]);
+    assert_eq!(entry.notes, vec!["fixed the deploy"]);
+    assert_eq!(entry.duration_minutes(), 90);
+
+    // Matches the entry a full parse produces for the same line
+    let data = parse_time_tracking_data("11-1 review", None, None);
+    assert_eq!(
+        parse_entry_line("  11-1 review  "),
+        Ok(data.entries[0].clone())
+    );
+
+    let err = parse_entry_line("25:70-8 project").unwrap_err();
+    assert_eq!(err.line, "25:70-8 project");
+    assert!(!err.reason.is_empty());
+    assert!(parse_entry_line("8-9").is_err());
+    assert!(parse_entry_line("some prose").is_err());
+    assert!(parse_entry_line("8-9, 2-3 review").is_err());
+}
+
+#[test]
+fn test_parse_entry_line_accepts_full_parser_forms() {
+    let expected = parse_entry_line("7-8 proj").unwrap();
+    assert_eq!(parse_entry_line("7–8 proj"), Ok(expected.clone()));
+    assert_eq!(parse_entry_line("7 to 8 proj"), Ok(expected));
+
+    let entry = parse_entry_line("1h30m review").unwrap();
+    assert_eq!(entry.duration, Some(90));
+    assert_eq!(entry.project, "review");
+    assert_eq!(parse_entry_line("1.5 review"), Ok(entry));
+}
+
+#[test]
+fn test_overnight_entries_cross_midnight() {
+    let config = ParserConfig::new().overnight(true);
+    let data = parse_with_config("10-6 shift", &config);
+
+    assert_eq!(data.total_minutes, 8 * 60);
+    let entry = &data.entries[0];
+    assert!(entry.crosses_midnight);
+    assert_eq!(
+        entry.start,
+        Time::new(10, 0).unwrap().with_meridiem(Meridiem::Pm)
+    );
+    assert_eq!(
+        entry.end,
+        Time::new(6, 0).unwrap().with_meridiem(Meridiem::Am)
+    );
+
+    // Without the option the same 8 hours is a guessed wrap with no flag
+    let data = parse_time_tracking_data("10-6 shift", None, None);
+    assert_eq!(data.total_minutes, 8 * 60);
+    assert!(!data.entries[0].crosses_midnight);
+
+    // Explicit PM on the start gives the same night shift
+    let data = parse_with_config("10pm-6 shift", &config);
+    assert_eq!(data.total_minutes, 8 * 60);
+    assert!(data.entries[0].crosses_midnight);
+
+    // Late in a long day, a wrap is past midnight
+    let data = parse_with_config("8-12 a\n12-6 b\n6-11 c\n11-1 d", &config);
+    assert!(data.entries[3].crosses_midnight);
+    assert_eq!(
+        data.entries[3].start,
+        Time::new(11, 0).unwrap().with_meridiem(Meridiem::Pm)
+    );
+    assert!(
+        data.entries[..3]
+            .iter()
+            .all(|entry| !entry.crosses_midnight)
+    );
+
+    // Explicit AM/PM sets the flag either way, and entries that don't wrap are left alone
+    assert!(
+        parse_entry_line("11pm-1am oncall")
+            .unwrap()
+            .crosses_midnight
+    );
+    let data = parse_with_config("8-12 alpha\n1-5 beta", &config);
+    assert!(data.entries.iter().all(|entry| !entry.crosses_midnight));
+    assert_eq!(data.entries[0].start.meridiem, None);
+
+    // A night shift keeps the date of the day it started on
+    let days = parse_multi_day_with_config("2024-03-04\n10-6 shift", &config);
+    assert_eq!(
+        days[0].1.entries[0].date,
+        Some(Date {
+            year: Some(2024),
+            month: 3,
+            day: 4
+        })
+    );
+    assert!(days[0].1.entries[0].crosses_midnight);
+}
+
+#[test]
+fn test_overnight_leaves_noon_crossings_alone() {
+    let config = ParserConfig::new().overnight(true);
+
+    let data = parse_with_config("9-11 a\n11-1 b", &config);
+    assert!(data.entries.iter().all(|entry| !entry.crosses_midnight));
+    assert_eq!(data.entries[1].start, Time::new(11, 0).unwrap());
+}
+
+#[test]
+fn test_parse_multi_day_warning_line_numbers() {
+    let input = "2024-03-04\n8-9 alpha\n\n2024-03-05\n8-9\n9-10 beta\n10-99 gamma";
+    let days = parse_multi_day(input);
+
+    assert_eq!(days.len(), 2);
+    assert!(days[0].1.warnings.is_empty());
+    let lines: Vec<usize> = days[1]
+        .1
+        .warnings
+        .iter()
+        .map(ParseWarning::line_number)
+        .collect();
+    assert_eq!(lines, vec![5, 7]);
+    assert_eq!(days[1].1.entries[1].line_number, 6);
+    let span = days[1].1.warnings[1].span().unwrap();
+    assert_eq!(&input[span], "10-99");
+}
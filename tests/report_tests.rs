@@ -0,0 +1,318 @@
+use time_tracking_parser::*;
+
+#[test]
+fn test_report_truncates_long_notes() {
+    let long_note = "x".repeat(100);
+    let input = format!("7-8 project1\n- {long_note}\n- short note");
+
+    let data = parse_time_tracking_data(&input, None, None);
+    let options = ReportOptions {
+        max_note_chars: Some(40),
+    };
+    let report = generate_report(&data, &options);
+
+    assert!(report.contains(&format!("- {}…\n", "x".repeat(40))));
+    assert!(!report.contains(&"x".repeat(41)));
+    assert!(report.contains("- short note\n"));
+
+    // Stored notes are left intact
+    assert_eq!(data.projects[0].notes[0], long_note);
+
+    // Without the option the full note is shown
+    let report = generate_report(&data, &ReportOptions::default());
+    assert!(report.contains(&format!("- {long_note}\n")));
+}
+
+#[test]
+fn test_truncate_chars_on_char_boundaries() {
+    assert_eq!(truncate_chars("héllo wörld", 7), "héllo w…");
+    assert_eq!(truncate_chars("日本語のメモ", 3), "日本語…");
+    assert_eq!(truncate_chars("short", 5), "short");
+    assert_eq!(truncate_chars("short", 10), "short");
+}
+
+#[test]
+fn test_to_csv() {
+    let input = r#"7-8 beta
+- fixed bug, deployed
+- said "hello"
+8-8:30 alpha"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+    let csv = data.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(
+        lines,
+        vec![
+            "project,minutes,hours,notes",
+            "alpha,30,0.50,",
+            r#"beta,60,1.00,"fixed bug, deployed; said ""hello""""#,
+        ]
+    );
+}
+
+fn assert_equivalent(original: &TimeTrackingData, reparsed: &TimeTrackingData) {
+    assert_eq!(reparsed.total_minutes, original.total_minutes);
+    assert_eq!(reparsed.dead_time_minutes, original.dead_time_minutes);
+    assert_eq!(reparsed.start_time, original.start_time);
+    assert_eq!(reparsed.end_time, original.end_time);
+    assert_eq!(reparsed.date, original.date);
+    assert_eq!(reparsed.projects, original.projects);
+    assert_eq!(reparsed.entries.len(), original.entries.len());
+    for (original, reparsed) in original.entries.iter().zip(&reparsed.entries) {
+        assert_eq!(reparsed.start, original.start);
+        assert_eq!(reparsed.end, original.end);
+        assert_eq!(reparsed.project, original.project);
+        assert_eq!(reparsed.notes, original.notes);
+        assert_eq!(reparsed.date, original.date);
+        assert_eq!(reparsed.duration, original.duration);
+        assert_eq!(reparsed.tag, original.tag);
+    }
+}
+
+#[test]
+fn test_to_tracking_text_round_trip() {
+    let input = r#"Header text to skip
+
+7:30-8 someproject
+8-8:30 admin
+- discussing staffing with colleague
+8:30-11 someproject
+- investigated issue, pushed PR
+* pushed potential fix for component /build url handling
+11-12 other-project
+tech connect
+12-12:30 admin
+- 1:1 w/ coworker
+12:30-2:30 someproject
+- discussing work items and how to complete"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+    let text = data.to_tracking_text();
+
+    assert!(text.starts_with("7:30-8:00 someproject\n8:00-8:30 admin\n- discussing staffing"));
+    assert!(text.contains("11:00-12:00 other-project\n- tech connect\n"));
+
+    let reparsed = parse_time_tracking_data(&text, None, None);
+    assert!(reparsed.warnings.is_empty());
+    assert_equivalent(&data, &reparsed);
+}
+
+#[test]
+fn test_to_tracking_text_keeps_dates_and_meridiem() {
+    let input = r#"6/3 11:45pm-12:15am oncall
+- paged"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+    let text = data.to_tracking_text();
+    assert_eq!(text, "6/3 11:45pm-12:15am oncall\n- paged\n");
+
+    assert_equivalent(&data, &parse_time_tracking_data(&text, None, None));
+}
+
+#[test]
+fn test_to_tracking_text_keeps_leading_tags() {
+    let input = "SC 8-9 proj\n- pairing\nJD 6/3 9-10:30 proj\n10:30-11 untagged";
+    let config = ParserConfig::new().leading_tag(true);
+
+    let data = parse_with_config(input, &config);
+    let text = data.to_tracking_text();
+    assert_eq!(
+        text,
+        "SC 8:00-9:00 proj\n- pairing\nJD 6/3 9:00-10:30 proj\n10:30-11:00 untagged\n"
+    );
+
+    let reparsed = parse_with_config(&text, &config);
+    assert_equivalent(&data, &reparsed);
+    assert_eq!(reparsed.minutes_by_tag(), data.minutes_by_tag());
+    assert_eq!(reparsed.minutes_by_tag()["SC"], 60);
+}
+
+#[test]
+fn test_to_markdown() {
+    let input = r#"7:30-8 someproject
+- investigated BTS-446
+8-8:30 admin
+8:30-9 someproject
+- pushed a fix"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+    let markdown = data.to_markdown();
+
+    assert!(markdown.starts_with("# Time Tracking Report\n"));
+    assert!(markdown.contains("- **Start Time:** 7:30\n"));
+    assert!(markdown.contains("- **Total Working Time:** 1:30 (1.50 hrs)\n"));
+    assert!(markdown.contains("| Billing Code | Time | Hours |\n| --- | --- | --- |\n"));
+    assert!(markdown.contains("| admin | 0:30 | 0.50 |\n| someproject | 1:00 | 1.00 |\n"));
+    assert!(markdown.contains("## someproject\n\n- investigated BTS-446\n- pushed a fix\n"));
+    assert!(!markdown.contains("## admin"));
+}
+
+#[test]
+fn test_to_markdown_empty() {
+    let data = parse_time_tracking_data("", None, None);
+    assert_eq!(data.to_markdown(), "# Time Tracking Report\n");
+}
+
+#[test]
+fn test_to_markdown_truncates_notes() {
+    let input = format!("7-8 project1\n- {}", "y".repeat(100));
+
+    let data = parse_time_tracking_data(&input, None, None);
+    let markdown = data.to_markdown_with(&ReportOptions {
+        max_note_chars: Some(40),
+    });
+
+    assert!(markdown.contains(&format!("- {}…\n", "y".repeat(40))));
+}
+
+#[test]
+fn test_to_stable_text_ignores_entry_order() {
+    let first = parse_time_tracking_data(
+        "8-9 zeta\n- write docs\n- fix bug\n9-10:30 alpha\n- review",
+        None,
+        None,
+    );
+    let second = parse_time_tracking_data(
+        "1-2:30 alpha\n- review\n2:30-3 zeta\n- fix bug\n3-3:30 zeta\n- write docs",
+        None,
+        None,
+    );
+
+    assert_eq!(first.to_stable_text(), second.to_stable_text());
+    assert_eq!(
+        first.to_stable_text(),
+        "Total: 2:30 (2.50 hrs)\n\nalpha: 1:30 (1.50 hrs)\n- review\n\nzeta: 1:00 (1.00 hrs)\n- fix bug\n- write docs\n"
+    );
+}
+
+#[test]
+fn test_to_tracking_text_keeps_durations() {
+    let data = parse_time_tracking_data("8-9 standup\n1h5m review\n- PR feedback", None, None);
+    let text = data.to_tracking_text();
+
+    assert_eq!(text, "8:00-9:00 standup\n1h5m review\n- PR feedback\n");
+    assert_equivalent(&data, &parse_time_tracking_data(&text, None, None));
+}
+
+#[test]
+fn test_to_ics() {
+    let input = r#"10:30-12:30 projectA
+- design review
+- fixed bug, shipped
+12:30-1 lunch-and-learn
+30m admin
+11-1 late-night
+- paged about the nightly import job failing on the reporting database replica"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+    // 2024-02-29T09:46:40Z
+    let created_at = 1_709_200_000;
+    let ics = data.to_ics(Date::new(Some(2024), 2, 29).unwrap(), created_at);
+
+    // The duration-only entry has no clock times, so it isn't exported. Afternoon times
+    // resolve past noon, the late entry rolls into the next day and the long note is
+    // folded at 75 octets.
+    assert_eq!(
+        ics,
+        "BEGIN:VCALENDAR\r\n\
+         VERSION:2.0\r\n\
+         PRODID:-//time-tracking-parser//EN\r\n\
+         BEGIN:VEVENT\r\n\
+         UID:20240229T103000-0@time-tracking-parser\r\n\
+         DTSTAMP:20240229T094640Z\r\n\
+         DTSTART:20240229T103000\r\n\
+         DTEND:20240229T123000\r\n\
+         SUMMARY:projectA\r\n\
+         DESCRIPTION:design review\\nfixed bug\\, shipped\r\n\
+         END:VEVENT\r\n\
+         BEGIN:VEVENT\r\n\
+         UID:20240229T123000-1@time-tracking-parser\r\n\
+         DTSTAMP:20240229T094640Z\r\n\
+         DTSTART:20240229T123000\r\n\
+         DTEND:20240229T130000\r\n\
+         SUMMARY:lunch-and-learn\r\n\
+         END:VEVENT\r\n\
+         BEGIN:VEVENT\r\n\
+         UID:20240229T230000-3@time-tracking-parser\r\n\
+         DTSTAMP:20240229T094640Z\r\n\
+         DTSTART:20240229T230000\r\n\
+         DTEND:20240301T010000\r\n\
+         SUMMARY:late-night\r\n\
+         DESCRIPTION:paged about the nightly import job failing on the reporting dat\r\n \
+         abase replica\r\n\
+         END:VEVENT\r\n\
+         END:VCALENDAR\r\n"
+    );
+    assert!(ics.split("\r\n").all(|line| line.len() <= 75));
+
+    // A date without a year takes the year of the timestamp, so output is deterministic
+    assert_eq!(
+        data.to_ics(Date::new(None, 2, 29).unwrap(), created_at),
+        ics
+    );
+
+    // Folding never splits a multi-byte character
+    let data = parse_time_tracking_data(&format!("8-9 {}", "é".repeat(40)), None, None);
+    let ics = data.to_ics(Date::new(Some(2024), 3, 1).unwrap(), created_at);
+    let summary = ics
+        .lines()
+        .find(|line| line.starts_with("SUMMARY"))
+        .unwrap();
+    assert_eq!(summary.len(), 8 + 33 * 2);
+    assert!(ics.contains(&format!("\r\n {}\r\n", "é".repeat(7))));
+}
+
+#[test]
+fn test_date_next_day() {
+    let next = |year, month, day| Date::new(year, month, day).unwrap().next_day();
+
+    assert_eq!(
+        next(Some(2024), 2, 28),
+        Date::new(Some(2024), 2, 29).unwrap()
+    );
+    assert_eq!(
+        next(Some(2023), 2, 28),
+        Date::new(Some(2023), 3, 1).unwrap()
+    );
+    assert_eq!(
+        next(Some(2024), 12, 31),
+        Date::new(Some(2025), 1, 1).unwrap()
+    );
+    assert_eq!(next(None, 4, 30), Date::new(None, 5, 1).unwrap());
+}
+
+#[test]
+fn test_to_html() {
+    let input = r#"8-9 alpha
+- fixed <script>alert("x")</script> & more
+9-9:30 R&D"#;
+
+    let html = parse_time_tracking_data(input, None, None).to_html();
+
+    assert!(html.starts_with("<section"));
+    assert!(html.ends_with("</section>\n"));
+    assert!(html.contains("Total Working Time: 1:30 (1.50 hrs)"));
+    assert!(html.contains("<tr><td>alpha</td><td>1:00</td><td>1.00</td></tr>"));
+    assert!(html.contains("<tr><td>R&amp;D</td><td>0:30</td><td>0.50</td></tr>"));
+    assert!(
+        html.contains(
+            "<li>fixed &lt;script&gt;alert(&quot;x&quot;)&lt;/script&gt; &amp; more</li>"
+        )
+    );
+    assert!(!html.contains("<script>"));
+}
+
+#[test]
+fn test_summary_line() {
+    let input = "7:30-10 alpha\n10-12 beta\n12-2:30 gamma";
+    let data = parse_time_tracking_data(input, None, None);
+
+    let line = data.summary_line();
+    assert_eq!(line, "7:30–2:30 · 7.00h worked · 0.00h idle · 3 projects");
+    assert!(line.contains("7.00h worked"));
+
+    let data = parse_time_tracking_data("1h30m review", None, None);
+    assert_eq!(data.summary_line(), "1.50h worked · 0.00h idle · 1 project");
+}
@@ -97,17 +97,177 @@ fn test_wasm_json_functions() {
     assert_eq!(parsed_data.projects.len(), 2);
 }
 
+#[test]
+fn test_json_camel_case_keys() {
+    let input = "7:30-8 someproject\n1-2 general";
+    let data = parse_time_tracking_data(input, None, None);
+
+    let json = data.to_json_camel().unwrap();
+    assert!(json.contains("\"totalMinutes\":90"));
+    assert!(json.contains("\"deadTimeMinutes\":"));
+    assert!(json.contains("\"lineNumber\":1"));
+    assert!(!json.contains('_'));
+    assert_eq!(parse_time_data_to_json_camel(input, None, None), json);
+
+    // Existing consumers still get snake_case
+    assert!(data.to_json().unwrap().contains("\"total_minutes\":90"));
+}
+
 #[test]
 fn test_json_with_warnings() {
     let input = r#"7-8 project1
 3-4 project2"#; // This should generate a warning
 
     let data = parse_time_tracking_data(input, None, None);
-    assert!(!data.warnings.is_empty());
+    assert!(!data.validation_warnings.is_empty());
 
     let json = data.to_json().expect("Should serialize even with warnings");
     let restored = TimeTrackingData::from_json(&json).expect("Should deserialize");
 
-    assert_eq!(restored.warnings, data.warnings);
-    assert!(!restored.warnings.is_empty());
+    assert_eq!(restored.validation_warnings, data.validation_warnings);
+    assert!(!restored.validation_warnings.is_empty());
+}
+
+#[test]
+fn test_notes_joined() {
+    let input = r#"7:30-8 someproject
+- investigated BTS-446
+- pushed a fix
+8-8:30 general"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+    let someproject = data
+        .projects
+        .iter()
+        .find(|p| p.name == "someproject")
+        .unwrap();
+    assert_eq!(
+        someproject.notes_joined("\n"),
+        "investigated BTS-446\npushed a fix"
+    );
+    assert_eq!(
+        someproject.notes_joined("; "),
+        "investigated BTS-446; pushed a fix"
+    );
+
+    let json = data.to_json_joined_notes("; ").unwrap();
+    assert!(json.contains(r#""notes":"investigated BTS-446; pushed a fix""#));
+    assert!(json.contains(r#""notes":"""#));
+
+    // The default JSON keeps the array form
+    let json = data.to_json().unwrap();
+    assert!(json.contains(r#""notes":["investigated BTS-446","pushed a fix"]"#));
+}
+
+#[test]
+fn test_serde_round_trip_preserves_entries() {
+    let input = r#"7:30-8 someproject
+- investigated BTS-446
+6/3 8-8:30 general"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+    let json = data.to_json().unwrap();
+    assert!(json.contains("\"entries\":["));
+
+    let restored = TimeTrackingData::from_json(&json).unwrap();
+    assert_eq!(restored.entries, data.entries);
+    assert_eq!(restored, data);
+}
+
+#[test]
+fn test_json_seconds() {
+    let input = r#"7:30-8 someproject
+8-8:30 general
+9-10 someproject"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+    let json = data.to_json_seconds().expect("Should serialize to JSON");
+
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["total_seconds"], data.total_minutes * 60);
+    assert_eq!(value["dead_time_seconds"], 30 * 60);
+    assert_eq!(value["projects"][0]["total_seconds"], 30 * 60);
+    assert!(value.get("total_minutes").is_none());
+
+    // Both forms deserialize to the same data
+    assert_eq!(TimeTrackingData::from_json(&json).unwrap(), data);
+    assert_eq!(
+        TimeTrackingData::from_json(&data.to_json().unwrap()).unwrap(),
+        data
+    );
+}
+
+#[test]
+fn test_from_json_rejects_non_objects() {
+    for json in ["[1,2]", "42", "\"s\"", "null"] {
+        assert!(TimeTrackingData::from_json(json).is_err(), "{json}");
+    }
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn test_yaml_round_trip() {
+    let input = r#"7:30-8 someproject
+- investigated BTS-446
+8-8:30 general
+- discussing staffing with colleague
+11-12 other-project
+- tech connect
+1-2pm"#;
+
+    let original_data = parse_time_tracking_data(input, None, None);
+    assert!(!original_data.warnings.is_empty());
+
+    let yaml = original_data.to_yaml().expect("Should serialize");
+    assert!(yaml.contains("total_minutes: 180"));
+
+    let restored_data = TimeTrackingData::from_yaml(&yaml).expect("Should deserialize");
+    assert_eq!(restored_data, original_data);
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn test_to_toml() {
+    let input = r#"7:30-8 someproject
+- investigated BTS-446
+8-8:30 general
+11-12 someproject"#;
+
+    let data = parse_time_tracking_data(input, None, None);
+    let text = data.to_toml().expect("Should serialize");
+
+    assert!(text.contains("start_time = \"7:30\""));
+    assert!(text.contains("end_time = \"12:00\""));
+    assert!(!text.contains("date"));
+
+    let value: toml::Value = toml::from_str(&text).expect("Should re-read");
+    assert_eq!(value["total_minutes"].as_integer(), Some(120));
+    let projects = value["projects"].as_array().unwrap();
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[1]["name"].as_str(), Some("someproject"));
+    assert_eq!(projects[1]["total_minutes"].as_integer(), Some(90));
+    assert_eq!(
+        projects[1]["notes"].as_array().unwrap()[0].as_str(),
+        Some("investigated BTS-446")
+    );
+}
+
+#[cfg(feature = "schema")]
+#[test]
+fn test_json_schema() {
+    let schema = json_schema();
+
+    assert_eq!(schema["title"], "TimeTrackingData");
+    let properties = &schema["properties"];
+    assert!(properties.get("projects").is_some());
+    assert_eq!(properties["total_minutes"]["type"], "integer");
+
+    let definitions = &schema["definitions"];
+    assert!(
+        definitions["ProjectSummary"]["properties"]
+            .get("notes")
+            .is_some()
+    );
+    assert!(definitions["Time"]["properties"].get("hour").is_some());
+    assert!(definitions.get("ParseWarning").is_some());
 }
@@ -1,3 +1,4 @@
+use std::str::FromStr;
 use time_tracking_parser::*;
 
 #[test]
@@ -73,6 +74,14 @@ fn test_format_duration_decimal() {
     assert_eq!(Time::format_duration_decimal(450), "7.50");
 }
 
+#[test]
+fn test_format_duration_decimal_prec() {
+    assert_eq!(Time::format_duration_decimal_prec(450, 1), "7.5");
+    assert_eq!(Time::format_duration_decimal_prec(450, 3), "7.500");
+    assert_eq!(Time::format_duration_decimal_prec(50, 3), "0.833");
+    assert_eq!(Time::format_duration_decimal_prec(90, 0), "2");
+}
+
 #[test]
 fn test_hour() {
     assert_eq!("1".parse::<Hour>().unwrap(), 1);
@@ -90,3 +99,373 @@ fn test_minute() {
     assert!("63".parse::<Minute>().is_err());
     assert!("-3".parse::<Minute>().is_err());
 }
+
+#[test]
+fn test_time_parse_errors() {
+    assert_eq!(
+        Time::new(13, 0),
+        Err(TimeParseError::HourOutOfRange {
+            hour: 13,
+            min: 1,
+            max: 12
+        })
+    );
+    assert_eq!(
+        Time::new(7, 60),
+        Err(TimeParseError::MinuteOutOfRange { minute: 60 })
+    );
+    assert_eq!(
+        Time::from_strings("7", "3o"),
+        Err(TimeParseError::NotANumber {
+            unit: "minute",
+            text: "3o".to_string()
+        })
+    );
+    assert!(matches!(
+        Hour::from_24_hour(24),
+        Err(TimeParseError::HourOutOfRange {
+            hour: 24,
+            max: 23,
+            ..
+        })
+    ));
+    assert!(matches!(
+        "x".parse::<Hour>(),
+        Err(TimeParseError::NotANumber { unit: "hour", .. })
+    ));
+    assert_eq!(
+        Second::try_from(75),
+        Err(TimeParseError::SecondOutOfRange { second: 75 })
+    );
+
+    // Messages are unchanged from when these were plain strings
+    assert_eq!(
+        Time::new(0, 30).unwrap_err().to_string(),
+        "Hour must be between 1 and 12, got 0"
+    );
+    assert_eq!(
+        Time::new(7, 75).unwrap_err().to_string(),
+        "Minute must be between 0 and 59, got 75"
+    );
+    let error: Box<dyn std::error::Error> = Box::new(Time::new(7, 75).unwrap_err());
+    assert!(error.to_string().starts_with("Minute"));
+}
+
+#[test]
+fn test_duration_minutes_with_meridiem() {
+    let start = Time::new(11, 45).unwrap().with_meridiem(Meridiem::Pm);
+    let end = Time::new(12, 15).unwrap().with_meridiem(Meridiem::Am);
+    assert_eq!(start.duration_minutes(&end), 30);
+
+    // Longer than 12 hours can only be expressed with explicit AM/PM
+    let start = Time::new(7, 0).unwrap().with_meridiem(Meridiem::Am);
+    let end = Time::new(8, 0).unwrap().with_meridiem(Meridiem::Pm);
+    assert_eq!(start.duration_minutes(&end), 13 * 60);
+    assert_eq!(
+        Time::new(7, 0)
+            .unwrap()
+            .duration_minutes(&Time::new(8, 0).unwrap()),
+        60
+    );
+}
+
+#[test]
+fn test_to_minutes_24() {
+    assert_eq!(Time::new(7, 30).unwrap().to_minutes_24(), None);
+    assert_eq!(
+        Time::new(12, 0)
+            .unwrap()
+            .with_meridiem(Meridiem::Am)
+            .to_minutes_24(),
+        Some(0)
+    );
+    assert_eq!(
+        Time::new(12, 0)
+            .unwrap()
+            .with_meridiem(Meridiem::Pm)
+            .to_minutes_24(),
+        Some(12 * 60)
+    );
+    assert_eq!(
+        Time::new(1, 30)
+            .unwrap()
+            .with_meridiem(Meridiem::Pm)
+            .to_minutes_24(),
+        Some(13 * 60 + 30)
+    );
+}
+
+#[test]
+fn test_meridiem_suffixes() {
+    for suffix in ["am", "AM", "a", "A", "a.m.", "am."] {
+        assert_eq!(suffix.parse::<Meridiem>().unwrap(), Meridiem::Am);
+    }
+    for suffix in ["pm", "PM", "p", "P", "p.m.", "Pm."] {
+        assert_eq!(suffix.parse::<Meridiem>().unwrap(), Meridiem::Pm);
+    }
+    assert!("x".parse::<Meridiem>().is_err());
+}
+
+#[test]
+fn test_iso8601_duration() {
+    assert_eq!(iso8601_duration(450), "PT7H30M");
+    assert_eq!(iso8601_duration(30), "PT30M");
+    assert_eq!(iso8601_duration(60), "PT1H");
+    assert_eq!(iso8601_duration(0), "PT0M");
+}
+
+#[test]
+fn test_hour_24() {
+    assert_eq!(
+        Hour::from_24_hour(0).unwrap(),
+        (12.try_into().unwrap(), Meridiem::Am)
+    );
+    assert_eq!(
+        Hour::from_24_hour(12).unwrap(),
+        (12.try_into().unwrap(), Meridiem::Pm)
+    );
+    assert_eq!(
+        Hour::from_24_hour(17).unwrap(),
+        (5.try_into().unwrap(), Meridiem::Pm)
+    );
+    assert!(Hour::from_24_hour(24).is_err());
+
+    for hour in 0..24 {
+        let (twelve, meridiem) = Hour::from_24_hour(hour).unwrap();
+        assert_eq!(twelve.to_24_hour(meridiem), hour);
+    }
+}
+
+#[test]
+fn test_time_new_24() {
+    let start = Time::new_24(9, 0).unwrap();
+    let end = Time::new_24(17, 30).unwrap();
+    assert_eq!(start.duration_minutes(&end), 510);
+    assert!(Time::new_24(24, 0).is_err());
+    assert!(Time::new_24(13, 60).is_err());
+}
+
+#[test]
+fn test_round_minutes() {
+    assert_eq!(Time::round_minutes(7, 6), 6);
+    assert_eq!(Time::round_minutes(9, 6), 12);
+    assert_eq!(Time::round_minutes(2, 6), 0);
+    assert_eq!(Time::round_minutes(37, 15), 30);
+    assert_eq!(Time::round_minutes(38, 15), 45);
+    assert_eq!(Time::round_minutes(38, 0), 38);
+}
+
+#[test]
+fn test_format_time_pads_minutes() {
+    let time = Time::new(7, 5).unwrap();
+    assert_eq!(format_time(&time), "7:05");
+    assert_eq!(format_time_option(Some(&time), "N/A"), "7:05");
+    assert_eq!(format_time(&Time::new(12, 0).unwrap()), "12:00");
+
+    let midnight = Time::from_strings("0", "5").unwrap();
+    assert_eq!(format_time_option(Some(&midnight), "N/A"), "00:05");
+    assert_eq!(format_time_option(None, "N/A"), "N/A");
+    assert_eq!(Time::new(7, 5).unwrap().minute.to_string(), "05");
+}
+
+#[test]
+fn test_time_ordering_uses_meridiem() {
+    let am = |hour| Time::new(hour, 0).unwrap().with_meridiem(Meridiem::Am);
+    let pm = |hour| Time::new(hour, 0).unwrap().with_meridiem(Meridiem::Pm);
+
+    assert!(am(11) < pm(1));
+    assert!(pm(12) < pm(1));
+    assert!(am(12) < am(11));
+    assert_eq!(am(11).max(pm(1)), pm(1));
+
+    // Without AM/PM on both sides, clock order still applies
+    assert!(Time::new(1, 0).unwrap() < Time::new(11, 0).unwrap());
+}
+
+#[test]
+fn test_time_ordering_agrees_with_eq() {
+    let at = |second: Option<u8>, meridiem: Option<Meridiem>| {
+        let time = Time::new(7, 30).unwrap();
+        let time = match second {
+            Some(second) => time.with_second(second.try_into().unwrap()),
+            None => time,
+        };
+        Time { meridiem, ..time }
+    };
+    let times: Vec<Time> = [None, Some(0), Some(15), Some(45)]
+        .into_iter()
+        .flat_map(|second| {
+            [None, Some(Meridiem::Am), Some(Meridiem::Pm)].map(|meridiem| at(second, meridiem))
+        })
+        .collect();
+
+    for a in &times {
+        for b in &times {
+            assert_eq!(
+                a.cmp(b) == std::cmp::Ordering::Equal,
+                a == b,
+                "{a:?} vs {b:?}"
+            );
+        }
+    }
+    assert!(at(Some(15), None) < at(Some(45), None));
+
+    // Hour 0 is kept as written, so it isn't equal to 12 and mustn't compare equal to it
+    let times: Vec<Time> = [("0", "0"), ("12", "0"), ("0", "30"), ("12", "30")]
+        .into_iter()
+        .map(|(hour, minute)| Time::from_strings(hour, minute).unwrap())
+        .flat_map(|time| {
+            [None, Some(Meridiem::Am), Some(Meridiem::Pm)].map(|meridiem| Time { meridiem, ..time })
+        })
+        .collect();
+    for a in &times {
+        for b in &times {
+            assert_eq!(
+                a.cmp(b) == std::cmp::Ordering::Equal,
+                a == b,
+                "{a:?} vs {b:?}"
+            );
+        }
+    }
+    let mut deduped = times.clone();
+    deduped.sort();
+    deduped.dedup();
+    assert_eq!(deduped.len(), times.len());
+    assert!(Time::from_strings("0", "0").unwrap() < Time::new(12, 0).unwrap());
+}
+
+#[test]
+fn test_format_time_compact() {
+    assert_eq!(format_time_compact(&Time::new(8, 0).unwrap()), "8");
+    assert_eq!(format_time_compact(&Time::new(8, 30).unwrap()), "8:30");
+    assert_eq!(format_time_compact(&Time::new(12, 5).unwrap()), "12:05");
+    assert_eq!(format_time(&Time::new(8, 0).unwrap()), "8:00");
+}
+
+#[test]
+fn test_time_ordering() {
+    assert!(Time::new(7, 30).unwrap() < Time::new(8, 0).unwrap());
+    assert!(Time::new(11, 59).unwrap() > Time::new(11, 0).unwrap());
+    assert_eq!(Time::new(7, 30).unwrap(), Time::new(7, 30).unwrap());
+    assert_eq!(
+        Time::new(7, 30).unwrap().cmp(&Time::new(7, 30).unwrap()),
+        std::cmp::Ordering::Equal
+    );
+
+    // 12:xx maps to 0 minutes, so it sorts first
+    let mut times = vec![
+        Time::new(8, 0).unwrap(),
+        Time::new(1, 15).unwrap(),
+        Time::new(12, 30).unwrap(),
+        Time::new(7, 45).unwrap(),
+    ];
+    times.sort();
+    assert_eq!(
+        times,
+        vec![
+            Time::new(12, 30).unwrap(),
+            Time::new(1, 15).unwrap(),
+            Time::new(7, 45).unwrap(),
+            Time::new(8, 0).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn test_time_from_minutes() {
+    assert_eq!(Time::from_minutes(0).unwrap(), Time::new(12, 0).unwrap());
+    assert_eq!(Time::from_minutes(450).unwrap(), Time::new(7, 30).unwrap());
+    assert_eq!(Time::from_minutes(719).unwrap(), Time::new(11, 59).unwrap());
+    assert!(Time::from_minutes(720).is_err());
+
+    for minutes in 0..720 {
+        assert_eq!(Time::from_minutes(minutes).unwrap().to_minutes(), minutes);
+    }
+}
+
+#[test]
+fn test_time_add_minutes() {
+    let time = Time::new(11, 30).unwrap();
+    assert_eq!(time.add_minutes(90), Time::new(1, 0).unwrap());
+    assert_eq!(time.add_minutes(30), Time::new(12, 0).unwrap());
+    assert_eq!(time.add_minutes(720), time);
+    assert_eq!(time.add_minutes(-720), time);
+    assert_eq!(
+        Time::new(1, 0).unwrap().add_minutes(-90),
+        Time::new(11, 30).unwrap()
+    );
+
+    // AM/PM times wrap around the whole day
+    let late = Time::new(11, 30).unwrap().with_meridiem(Meridiem::Pm);
+    assert_eq!(
+        late.add_minutes(90),
+        Time::new(1, 0).unwrap().with_meridiem(Meridiem::Am)
+    );
+    assert_eq!(
+        late.add_minutes(720),
+        Time::new(11, 30).unwrap().with_meridiem(Meridiem::Am)
+    );
+}
+
+#[test]
+fn test_time_display() {
+    assert_eq!(format!("{}", Time::new(7, 30).unwrap()), "7:30");
+    assert_eq!(Time::new(12, 5).unwrap().to_string(), "12:05");
+    assert_eq!(Time::new_24(13, 30).unwrap().to_string(), "1:30pm");
+    assert_eq!(format_time(&Time::new(7, 30).unwrap()), "7:30");
+
+    assert_eq!(Time::new(7, 30).unwrap().as_duration_string(), "7.50 hours");
+    assert_eq!(Time::new(1, 0).unwrap().as_duration_string(), "1 hour");
+}
+
+#[test]
+fn test_duration_seconds() {
+    let start = Time::new(7, 30)
+        .unwrap()
+        .with_second(15.try_into().unwrap());
+    let end = Time::new(7, 30)
+        .unwrap()
+        .with_second(45.try_into().unwrap());
+    assert_eq!(start.duration_seconds(&end), 30);
+    assert_eq!(start.duration_minutes(&end), 0);
+    assert_eq!(start.to_string(), "7:30:15");
+
+    // Without seconds the math is minute-based as before
+    let plain = Time::new(8, 0).unwrap();
+    assert_eq!(start.duration_seconds(&plain), 29 * 60 + 45);
+}
+
+#[test]
+fn test_entry_duration_methods_agree_across_noon() {
+    let data = parse_time_tracking_data("11-1 lunch-and-learn\n1-1:30 admin", None, None);
+    let entry = &data.entries[0];
+
+    assert_eq!(entry.duration_minutes(), 120);
+    assert_eq!(entry.start.chronological_duration_minutes(&entry.end), 120);
+    let entry = &data.entries[1];
+    assert_eq!(entry.start.chronological_duration_minutes(&entry.end), 30);
+}
+
+#[test]
+fn test_time_from_str() {
+    assert_eq!("7:30".parse::<Time>(), Ok(Time::new(7, 30).unwrap()));
+    assert_eq!(Time::from_str("8"), Ok(Time::new(8, 0).unwrap()));
+    assert_eq!(
+        "1:30pm".parse::<Time>(),
+        Ok(Time::new(1, 30).unwrap().with_meridiem(Meridiem::Pm))
+    );
+    assert!("25:00".parse::<Time>().is_err());
+    assert!("7:30:15:00".parse::<Time>().is_err());
+}
+
+#[test]
+fn test_time_leading_zero_hours() {
+    assert_eq!("07:30".parse::<Time>(), Ok(Time::new(7, 30).unwrap()));
+    assert_eq!("08".parse::<Time>(), Ok(Time::new(8, 0).unwrap()));
+
+    // Hour zero is midnight on a 24-hour clock, so it can only be am
+    let half_past_midnight = Time::new(12, 30).unwrap().with_meridiem(Meridiem::Am);
+    assert_eq!("00:30".parse::<Time>(), Ok(half_past_midnight));
+    assert_eq!("0:30am".parse::<Time>(), Ok(half_past_midnight));
+    assert!("00:30pm".parse::<Time>().is_err());
+}